@@ -0,0 +1,24 @@
+#!/bin/sh
+# Generates dist/sw.js from the sw.js template with a precache list of the
+# (hashed) build output. Run by Trunk as a post_build hook, see Trunk.toml.
+set -eu
+
+staging="${TRUNK_STAGING_DIR:?must be run as a Trunk hook}"
+source_dir="${TRUNK_SOURCE_DIR:-.}"
+public_url="${TRUNK_PUBLIC_URL:-/}"
+
+cd "$staging"
+assets=$(find . -type f ! -name sw.js | sed 's|^\./||' | sort)
+
+# Cache version changes whenever any build output changes
+version=$(cat $assets | cksum | cut -d ' ' -f 1)
+
+list="\"$public_url\""
+for asset in $assets; do
+  list="$list, \"$public_url$asset\""
+done
+
+sed \
+  -e "s|__CACHE_VERSION__|$version|" \
+  -e "s|__PRECACHE_ASSETS__|[$list]|" \
+  "$source_dir/sw.js" > sw.js
@@ -0,0 +1,184 @@
+use crate::core::clock::{civil_date, weekday};
+use leptos::prelude::*;
+
+/// Size of the bar and line charts, in SVG units
+const WIDTH: f64 = 320.0;
+const HEIGHT: f64 = 120.0;
+/// Room under the plot for the labels
+const LABEL_SPACE: f64 = 16.0;
+
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+/// Bars for a series of values; empty labels are skipped
+#[component]
+pub fn BarChart(
+    values: Vec<usize>,
+    labels: Vec<String>,
+    /// Value of a full-height bar, the largest value if not given
+    #[prop(optional)]
+    max: Option<usize>,
+) -> impl IntoView {
+    let max = max
+        .unwrap_or_else(|| values.iter().copied().max().unwrap_or(0))
+        .max(1) as f64;
+    let slot = WIDTH / values.len().max(1) as f64;
+    let plot = HEIGHT - LABEL_SPACE;
+
+    let bars = values
+        .into_iter()
+        .enumerate()
+        .map(|(i, value)| {
+            let height = value as f64 / max * plot;
+            view! {
+                <rect
+                    class="chart-bar"
+                    x=i as f64 * slot + slot * 0.15
+                    y=plot - height
+                    width=slot * 0.7
+                    height=height
+                >
+                    <title>{value}</title>
+                </rect>
+            }
+        })
+        .collect::<Vec<_>>();
+    let labels = labels
+        .into_iter()
+        .enumerate()
+        .filter(|(_, label)| !label.is_empty())
+        .map(|(i, label)| {
+            view! {
+                <text class="chart-label" x=i as f64 * slot + slot / 2.0 y=HEIGHT - 4.0 text-anchor="middle">
+                    {label}
+                </text>
+            }
+        })
+        .collect::<Vec<_>>();
+
+    view! {
+        <svg class="chart" viewBox=format!("0 0 {} {}", WIDTH, HEIGHT) role="img">
+            <line class="chart-axis" x1=0 y1=plot x2=WIDTH y2=plot/>
+            {bars}
+            {labels}
+        </svg>
+    }
+}
+
+/// A line through a series of values, starting from zero, labelled with
+/// its last value
+#[component]
+pub fn LineChart(values: Vec<usize>, labels: Vec<String>) -> impl IntoView {
+    let max = values.iter().copied().max().unwrap_or(0).max(1) as f64;
+    let step = WIDTH / values.len().saturating_sub(1).max(1) as f64;
+    let plot = HEIGHT - LABEL_SPACE;
+    let point = |i: usize, value: usize| (i as f64 * step, plot - value as f64 / max * plot);
+
+    let points = values
+        .iter()
+        .enumerate()
+        .map(|(i, value)| {
+            let (x, y) = point(i, *value);
+            format!("{:.1},{:.1}", x, y)
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+    let area = format!("0,{} {} {:.1},{}", plot, points, WIDTH, plot);
+    let last = values.last().map(|value| {
+        let (x, y) = point(values.len() - 1, *value);
+        view! {
+            <text class="chart-value" x=x - 4.0 y=(y - 4.0).max(10.0) text-anchor="end">{*value}</text>
+        }
+    });
+    let labels = labels
+        .into_iter()
+        .enumerate()
+        .filter(|(_, label)| !label.is_empty())
+        .map(|(i, label)| {
+            let anchor = if i == 0 { "start" } else { "end" };
+            view! {
+                <text class="chart-label" x=i as f64 * step y=HEIGHT - 4.0 text-anchor=anchor>{label}</text>
+            }
+        })
+        .collect::<Vec<_>>();
+
+    view! {
+        <svg class="chart" viewBox=format!("0 0 {} {}", WIDTH, HEIGHT) role="img">
+            <polygon class="chart-area" points=area/>
+            <polyline class="chart-line" points=points/>
+            {last}
+            {labels}
+        </svg>
+    }
+}
+
+/// Side of a heatmap cell, and the gap between cells
+const CELL: f64 = 11.0;
+const GAP: f64 = 2.0;
+/// Room for the weekday labels on the left and the months on top
+const LEFT: f64 = 14.0;
+const TOP: f64 = 12.0;
+
+/// A calendar of daily counts, one column per week from Monday to Sunday
+#[component]
+pub fn CalendarHeatmap(
+    /// Day since 1970-01-01 of the first count; a Monday
+    first_day: i64,
+    counts: Vec<usize>,
+) -> impl IntoView {
+    let max = counts.iter().copied().max().unwrap_or(0).max(1);
+    let weeks = counts.len().div_ceil(7);
+    let pitch = CELL + GAP;
+    let width = LEFT + weeks as f64 * pitch;
+    let height = TOP + 7.0 * pitch;
+
+    let cells = counts
+        .iter()
+        .enumerate()
+        .map(|(i, count)| {
+            let day = first_day + i as i64;
+            let (year, month, day_of_month) = civil_date(day);
+            // Four shades, with any study at all at least the lightest
+            let level = (count * 4).div_ceil(max);
+            view! {
+                <rect
+                    class=format!("heatmap-cell level-{}", level)
+                    x=LEFT + (i / 7) as f64 * pitch
+                    y=TOP + weekday(day) as f64 * pitch
+                    width=CELL
+                    height=CELL
+                    rx=2
+                >
+                    <title>{format!("{} {} {}: {} cards", day_of_month, MONTHS[month as usize - 1], year, count)}</title>
+                </rect>
+            }
+        })
+        .collect::<Vec<_>>();
+    // Name each month above the week it starts in
+    let months = (0..weeks)
+        .filter_map(|week| {
+            let (_, month, day_of_month) = civil_date(first_day + week as i64 * 7);
+            (day_of_month <= 7).then(|| {
+                view! {
+                    <text class="chart-label" x=LEFT + week as f64 * pitch y=TOP - 3.0>
+                        {MONTHS[month as usize - 1]}
+                    </text>
+                }
+            })
+        })
+        .collect::<Vec<_>>();
+    let weekdays = [(0, "M"), (2, "W"), (4, "F")].map(|(row, name)| {
+        view! {
+            <text class="chart-label" x=0 y=TOP + row as f64 * pitch + CELL - 2.0>{name}</text>
+        }
+    });
+
+    view! {
+        <svg class="chart heatmap" viewBox=format!("0 0 {} {}", width, height) role="img">
+            {months}
+            {weekdays}
+            {cells}
+        </svg>
+    }
+}
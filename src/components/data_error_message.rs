@@ -0,0 +1,110 @@
+use crate::core::language::UI_LANGUAGE;
+use crate::data::{DataError, LearningDirection, STAGE_COUNT, load_stage_meta};
+use leptos::prelude::*;
+use leptos_router::components::A;
+
+/// Error message offering a way forward for each kind of data error
+#[component]
+pub fn DataErrorMessage(error: DataError, direction: LearningDirection) -> impl IntoView {
+    let dir = direction.as_query();
+    let stage_link = move |stage: u32| {
+        let title = load_stage_meta(stage)
+            .map(|m| format!("{} {}", m.emoji, m.title.get(UI_LANGUAGE)))
+            .unwrap_or_else(|_| format!("Stage {}", stage));
+        view! {
+            <A href=format!("/vocabulary/{}?dir={}", stage, dir) attr:class="nav-btn error-action">
+                {title}
+            </A>
+        }
+        .into_any()
+    };
+    let grammar_link = || {
+        view! {
+            <A href="/grammar" attr:class="nav-btn error-action">"Other grammar topics"</A>
+        }
+        .into_any()
+    };
+    let grammar_error = matches!(
+        error,
+        DataError::UnknownTopic { .. }
+            | DataError::GrammarParse { .. }
+            | DataError::InvalidExercise { .. }
+            | DataError::UnknownLesson { .. }
+            | DataError::InvalidLesson { .. }
+    );
+    let all_stages = (!grammar_error).then(|| {
+        view! {
+            <A href=format!("/vocabulary?dir={}", dir) attr:class="error-link">"All stages"</A>
+        }
+    });
+
+    let (message, action) = match &error {
+        DataError::UnknownStage { stage } => {
+            let nearest = (*stage).clamp(1, STAGE_COUNT);
+            (
+                format!(
+                    "Stage {} doesn't exist. The closest one is stage {}.",
+                    stage, nearest
+                ),
+                Some(stage_link(nearest)),
+            )
+        }
+        DataError::IndexOutOfRange { stage, len, .. } => (
+            format!(
+                "That card isn't in stage {}, which has {} cards.",
+                stage, len
+            ),
+            Some(stage_link(*stage)),
+        ),
+        DataError::UnknownLanguage { language } => (
+            format!("Translations for \"{}\" aren't available yet.", language),
+            None,
+        ),
+        DataError::Parse { stage, .. } | DataError::MisalignedTranslations { stage, .. } => {
+            let other = if *stage < STAGE_COUNT {
+                stage + 1
+            } else {
+                stage - 1
+            };
+            (
+                format!(
+                    "Stage {} has a content problem and can't be shown right now.",
+                    stage
+                ),
+                Some(stage_link(other)),
+            )
+        }
+        DataError::UnknownTopic { topic } => (
+            format!("There are no exercises on \"{}\".", topic),
+            Some(grammar_link()),
+        ),
+        DataError::UnknownLesson { lesson } => (
+            format!("There is no lesson called \"{}\".", lesson),
+            Some(grammar_link()),
+        ),
+        DataError::GrammarParse { .. }
+        | DataError::InvalidExercise { .. }
+        | DataError::InvalidLesson { .. } => (
+            "This grammar content has a problem and can't be shown right now.".to_string(),
+            Some(grammar_link()),
+        ),
+    };
+    let details = matches!(
+        error,
+        DataError::Parse { .. }
+            | DataError::MisalignedTranslations { .. }
+            | DataError::GrammarParse { .. }
+            | DataError::InvalidExercise { .. }
+            | DataError::InvalidLesson { .. }
+    )
+    .then(|| view! { <p class="error-details">{error.to_string()}</p> });
+
+    view! {
+        <div class="error-message">
+            <p>{message}</p>
+            {details}
+            {action}
+            {all_stages}
+        </div>
+    }
+}
@@ -0,0 +1,18 @@
+use crate::core::headword::locate_headword;
+use crate::core::language::Language;
+use leptos::prelude::*;
+
+/// Example sentence with the headword (or the inflected form used in it)
+/// emphasised; shown as plain text when the headword can't be found
+#[component]
+pub fn HighlightedExample(headword: String, example: String, language: Language) -> impl IntoView {
+    match locate_headword(&headword, &example, language) {
+        Some(range) => view! {
+            {example[..range.start].to_string()}
+            <mark class="headword">{example[range.clone()].to_string()}</mark>
+            {example[range.end..].to_string()}
+        }
+        .into_any(),
+        None => example.into_any(),
+    }
+}
@@ -0,0 +1,20 @@
+use crate::core::SettingsContext;
+use crate::core::ipa::transcribe_text;
+use leptos::prelude::*;
+
+/// Broad IPA of Spanish text in the chosen accent, if enabled in settings
+#[component]
+pub fn IpaTranscription(text: String) -> impl IntoView {
+    let settings = expect_context::<SettingsContext>();
+
+    move || {
+        let settings = settings.settings.read();
+        settings.show_ipa.then(|| {
+            view! {
+                <p class="card-ipa" title=settings.dialect.name()>
+                    {format!("/{}/", transcribe_text(&text, settings.dialect))}
+                </p>
+            }
+        })
+    }
+}
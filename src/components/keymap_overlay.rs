@@ -0,0 +1,58 @@
+use crate::core::keymap::KeymapContext;
+use leptos::ev;
+use leptos::prelude::*;
+
+/// Global keyboard listener with a `?` overlay listing the active shortcuts
+#[component]
+pub fn KeymapOverlay() -> impl IntoView {
+    let keymap = expect_context::<KeymapContext>();
+    let (open, set_open) = signal(false);
+
+    let handle = window_event_listener(ev::keydown, move |ev| {
+        if ev.ctrl_key() || ev.meta_key() || ev.alt_key() || ev.repeat() || is_typing() {
+            return;
+        }
+        let key = ev.key();
+        match key.as_str() {
+            "?" => set_open.update(|o| *o = !*o),
+            "Escape" => set_open.set(false),
+            _ => {
+                if !keymap.dispatch(&key) {
+                    return;
+                }
+            }
+        }
+        ev.prevent_default();
+    });
+    on_cleanup(move || handle.remove());
+
+    view! {
+        <Show when=move || open.get()>
+            <div class="keymap-overlay" on:click=move |_| set_open.set(false)>
+                <div class="keymap-panel">
+                    <h2>"Keyboard shortcuts"</h2>
+                    <table class="keymap-table">
+                        {move || keymap.get_all().into_iter().map(|binding| view! {
+                            <tr>
+                                <td><kbd>{binding.label}</kbd></td>
+                                <td>{binding.description}</td>
+                            </tr>
+                        }).collect::<Vec<_>>()}
+                        <tr>
+                            <td><kbd>"?"</kbd></td>
+                            <td>"Show or hide shortcuts"</td>
+                        </tr>
+                    </table>
+                </div>
+            </div>
+        </Show>
+    }
+}
+
+/// Whether the user is typing into a form field
+fn is_typing() -> bool {
+    document()
+        .active_element()
+        .map(|el| matches!(el.tag_name().as_str(), "INPUT" | "TEXTAREA" | "SELECT"))
+        .unwrap_or(false)
+}
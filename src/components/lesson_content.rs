@@ -0,0 +1,97 @@
+use crate::core::speech;
+use crate::data::LessonBlock;
+use leptos::prelude::*;
+
+/// Renders the blocks of a grammar lesson
+#[component]
+pub fn LessonContent(blocks: Vec<LessonBlock>) -> impl IntoView {
+    blocks
+        .into_iter()
+        .map(|block| match block {
+            LessonBlock::Heading { text } => view! { <h2 class="lesson-heading">{text}</h2> }.into_any(),
+            LessonBlock::Paragraph { text } => view! { <p class="lesson-paragraph">{text}</p> }.into_any(),
+            LessonBlock::Table { header, rows } => view! {
+                <table class="lesson-table">
+                    <thead>
+                        <tr>{header.into_iter().map(|cell| view! { <th>{cell}</th> }).collect::<Vec<_>>()}</tr>
+                    </thead>
+                    <tbody>
+                        {rows.into_iter().map(|row| view! {
+                            <tr>{row.into_iter().map(|cell| view! { <td>{cell}</td> }).collect::<Vec<_>>()}</tr>
+                        }).collect::<Vec<_>>()}
+                    </tbody>
+                </table>
+            }.into_any(),
+            LessonBlock::Example { spanish, english } => {
+                let spoken = spanish.clone();
+                view! {
+                    <div class="lesson-example">
+                        <button
+                            class="audio-button-small"
+                            title="Listen"
+                            on:click=move |_| speech::speak(&spoken, "es-ES")
+                        >
+                            "🔊"
+                        </button>
+                        <div>
+                            <p class="lesson-example-spanish">{spanish}</p>
+                            <p class="lesson-example-english">{english}</p>
+                        </div>
+                    </div>
+                }.into_any()
+            }
+            LessonBlock::Quiz { question, choices, answer, explanation } => view! {
+                <LessonQuiz question choices answer explanation/>
+            }.into_any(),
+        })
+        .collect::<Vec<_>>()
+}
+
+/// A multiple choice question inside a lesson
+#[component]
+fn LessonQuiz(
+    question: String,
+    choices: Vec<String>,
+    answer: String,
+    explanation: String,
+) -> impl IntoView {
+    let (picked, set_picked) = signal(None::<String>);
+
+    let buttons = choices
+        .into_iter()
+        .map(|choice| {
+            let is_answer = choice == answer;
+            let label = choice.clone();
+            let state = {
+                let choice = choice.clone();
+                move || match picked.get() {
+                    Some(_) if is_answer => "cloze-choice correct",
+                    Some(p) if p == choice => "cloze-choice incorrect",
+                    _ => "cloze-choice",
+                }
+            };
+            view! {
+                <button
+                    class=state
+                    disabled=move || picked.get().is_some()
+                    on:click=move |_| set_picked.set(Some(choice.clone()))
+                >
+                    {label}
+                </button>
+            }
+        })
+        .collect::<Vec<_>>();
+
+    view! {
+        <div class="lesson-quiz">
+            <p class="lesson-quiz-question">{question}</p>
+            <div class="cloze-choices">{buttons}</div>
+            {move || picked.get().map(|p| view! {
+                <p class=if p == answer { "cloze-result correct" } else { "cloze-result incorrect" }>
+                    {if p == answer { "✓ " } else { "✗ " }}
+                    {explanation.clone()}
+                </p>
+            })}
+        </div>
+    }
+}
@@ -1,2 +1,23 @@
+pub mod charts;
+pub mod data_error_message;
+pub mod highlighted_example;
+pub mod ipa_transcription;
+pub mod keymap_overlay;
+pub mod lesson_content;
+pub mod qr_code;
+pub mod session_summary;
+pub mod syllable_breakdown;
+pub mod update_banner;
 pub mod vocabulary_card;
+
+pub use charts::{BarChart, CalendarHeatmap, LineChart};
+pub use data_error_message::DataErrorMessage;
+pub use highlighted_example::HighlightedExample;
+pub use ipa_transcription::IpaTranscription;
+pub use keymap_overlay::KeymapOverlay;
+pub use lesson_content::LessonContent;
+pub use qr_code::QrCodeImage;
+pub use session_summary::SessionSummary;
+pub use syllable_breakdown::SyllableBreakdown;
+pub use update_banner::UpdateBanner;
 pub use vocabulary_card::VocabularyCard;
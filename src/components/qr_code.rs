@@ -0,0 +1,38 @@
+use crate::core::qr::QrCode;
+use leptos::prelude::*;
+use std::fmt::Write;
+
+/// Light modules kept around the code so scanners can find its edges
+const QUIET_ZONE: usize = 4;
+
+/// QR code rendered as an inline SVG image
+#[component]
+pub fn QrCodeImage(#[prop(into)] text: String) -> impl IntoView {
+    match QrCode::encode_text(&text) {
+        Ok(code) => {
+            let size = code.size() + QUIET_ZONE * 2;
+            let mut path = String::new();
+            for y in 0..code.size() {
+                for x in 0..code.size() {
+                    if code.is_dark(x, y) {
+                        let _ = write!(path, "M{},{}h1v1h-1z", x + QUIET_ZONE, y + QUIET_ZONE);
+                    }
+                }
+            }
+
+            view! {
+                <svg
+                    class="qr-code"
+                    xmlns="http://www.w3.org/2000/svg"
+                    viewBox=format!("0 0 {} {}", size, size)
+                    shape-rendering="crispEdges"
+                >
+                    <rect width="100%" height="100%" fill="#ffffff"/>
+                    <path d=path fill="#000000"/>
+                </svg>
+            }
+            .into_any()
+        }
+        Err(e) => view! { <p class="error-message">{e}</p> }.into_any(),
+    }
+}
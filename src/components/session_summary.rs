@@ -0,0 +1,74 @@
+use crate::core::clock;
+use crate::core::session::{SessionStats, format_duration};
+use leptos::prelude::*;
+use leptos_router::components::A;
+
+/// End-of-session screen for a pass through a stage's cards
+#[component]
+pub fn SessionSummary(
+    stats: SessionStats,
+    card_count: usize,
+    /// Word and translation of each missed card
+    missed_words: Vec<(String, String)>,
+    on_review_missed: Callback<()>,
+    on_restart: Callback<()>,
+    /// Link to the next stage, if there is one
+    next_stage_href: Option<String>,
+) -> impl IntoView {
+    let accuracy = stats.accuracy();
+    let has_missed = !missed_words.is_empty();
+
+    view! {
+        <div class="session-summary">
+            <h2>"Session complete"</h2>
+            <dl class="session-stats">
+                <div>
+                    <dt>"Time spent"</dt>
+                    <dd>{format_duration(stats.duration_ms(clock::now_ms()))}</dd>
+                </div>
+                <div>
+                    <dt>"Cards seen"</dt>
+                    <dd>{format!("{} / {}", stats.seen_count(), card_count)}</dd>
+                </div>
+                <div>
+                    <dt>"Revealed without the example"</dt>
+                    <dd>{stats.revealed_without_example_count()}</dd>
+                </div>
+                <div>
+                    <dt>"Favorites added"</dt>
+                    <dd>{stats.favorites_added_count()}</dd>
+                </div>
+                {accuracy.map(|accuracy| view! {
+                    <div>
+                        <dt>"Accuracy"</dt>
+                        <dd>{format!("{}%", accuracy)}</dd>
+                    </div>
+                })}
+            </dl>
+
+            <div class="session-actions">
+                {has_missed.then(|| view! {
+                    <button class="nav-btn" on:click=move |_| on_review_missed.run(())>"Review missed"</button>
+                })}
+                <button class="nav-btn" on:click=move |_| on_restart.run(())>"Restart shuffled"</button>
+                {next_stage_href.map(|href| view! {
+                    <A href=href attr:class="nav-btn">"Next stage →"</A>
+                })}
+            </div>
+
+            {has_missed.then(|| view! {
+                <section class="mistake-review">
+                    <h2>"Missed cards"</h2>
+                    <ul>
+                        {missed_words.into_iter().map(|(word, translation)| view! {
+                            <li>
+                                <p class="mistake-sentence">{word}</p>
+                                <p>{translation}</p>
+                            </li>
+                        }).collect::<Vec<_>>()}
+                    </ul>
+                </section>
+            })}
+        </div>
+    }
+}
@@ -0,0 +1,39 @@
+use crate::core::syllables::syllabify;
+use leptos::prelude::*;
+
+/// Spanish text split into syllables (`a·MI·go`), stressed syllables marked
+#[component]
+pub fn SyllableBreakdown(text: String) -> impl IntoView {
+    let mut parts = Vec::new();
+    let mut rest = text.as_str();
+    while !rest.is_empty() {
+        let split = rest
+            .find(|c: char| c.is_alphabetic() != rest.starts_with(char::is_alphabetic))
+            .unwrap_or(rest.len());
+        let (part, tail) = rest.split_at(split);
+        parts.push(part.to_string());
+        rest = tail;
+    }
+
+    let words = parts.into_iter().map(|part| match syllabify(&part) {
+        Some(word) if word.syllables.len() > 1 => {
+            let stress = word.stress().name();
+            let syllables = word
+                .syllables
+                .iter()
+                .enumerate()
+                .map(|(i, syllable)| {
+                    let class = if i == word.stressed { "stressed" } else { "" };
+                    view! {
+                        {(i > 0).then_some("·")}
+                        <span class=class>{syllable.clone()}</span>
+                    }
+                })
+                .collect::<Vec<_>>();
+            view! { <span title=stress>{syllables}</span> }.into_any()
+        }
+        _ => part.into_any(),
+    });
+
+    view! { <p class="card-syllables">{words.collect::<Vec<_>>()}</p> }
+}
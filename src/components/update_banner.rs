@@ -0,0 +1,47 @@
+use leptos::prelude::*;
+
+/// Banner shown when the service worker has installed a newer build
+#[component]
+pub fn UpdateBanner() -> impl IntoView {
+    #[allow(unused_variables)]
+    let (update_available, set_update_available) = signal(false);
+
+    #[cfg(target_arch = "wasm32")]
+    {
+        use wasm_bindgen::prelude::*;
+        #[wasm_bindgen]
+        extern "C" {
+            #[wasm_bindgen(js_namespace = window)]
+            fn on_sw_update(callback: &Closure<dyn Fn()>);
+        }
+        let callback = Closure::<dyn Fn()>::new(move || set_update_available.set(true));
+        on_sw_update(&callback);
+        // The service worker can report an update at any point in the session
+        callback.forget();
+    }
+
+    // Activate the waiting service worker, which reloads the page
+    let reload = move |_| {
+        #[cfg(target_arch = "wasm32")]
+        {
+            use wasm_bindgen::prelude::*;
+            #[wasm_bindgen]
+            extern "C" {
+                #[wasm_bindgen(js_namespace = window)]
+                fn apply_sw_update();
+            }
+            apply_sw_update();
+        }
+    };
+
+    view! {
+        <Show when=move || update_available.get()>
+            <div class="update-banner">
+                <span>"Update available"</span>
+                <button class="update-button" on:click=reload>
+                    "Reload"
+                </button>
+            </div>
+        </Show>
+    }
+}
@@ -1,5 +1,11 @@
+use crate::components::{HighlightedExample, IpaTranscription, SyllableBreakdown};
+use crate::core::gesture::{Swipe, classify_swipe};
+use crate::core::keymap::{KeyBinding, use_key_bindings};
+use crate::core::language::Language;
+use crate::core::speech;
 use crate::data::LearningDirection;
 use leptos::prelude::*;
+use std::rc::Rc;
 
 /// Shared vocabulary card component
 #[component]
@@ -14,6 +20,20 @@ pub fn VocabularyCard<F>(
     direction: LearningDirection,
     #[prop(optional)] stage: Option<u32>,
     on_toggle_favorite: F,
+    /// Called on swipe right
+    #[prop(optional)]
+    on_previous: Option<Callback<()>>,
+    /// Called on swipe left
+    #[prop(optional)]
+    on_next: Option<Callback<()>>,
+    /// Review modes: once the translation is shown, swipe right grades the
+    /// card as known (`true`) and swipe left as unknown (`false`)
+    #[prop(optional)]
+    on_grade: Option<Callback<bool>>,
+    /// Called when the translation is shown, with whether the example was
+    /// shown before it
+    #[prop(optional)]
+    on_reveal_translation: Option<Callback<bool>>,
 ) -> impl IntoView
 where
     F: Fn() + 'static,
@@ -30,21 +50,7 @@ where
     });
 
     // Speak word using Web Speech API
-    #[allow(unused_variables)]
-    let speak = move |text: String, lang: &str| {
-        #[allow(unused_variables)]
-        let lang = lang.to_string();
-        #[cfg(target_arch = "wasm32")]
-        {
-            use wasm_bindgen::prelude::*;
-            #[wasm_bindgen]
-            extern "C" {
-                #[wasm_bindgen(js_namespace = window)]
-                fn speak_text(text: &str, lang: &str);
-            }
-            speak_text(&text, &lang);
-        }
-    };
+    let speak = move |text: String, lang: &str| speech::speak(&text, lang);
 
     let source_lang = match direction {
         LearningDirection::SpanishToEnglish => "es-ES",
@@ -56,13 +62,164 @@ where
         LearningDirection::EnglishToSpanish => "es-ES",
     };
 
+    let on_toggle_favorite = Rc::new(on_toggle_favorite);
+
+    let reveal_translation = move || {
+        if show_translation.get_untracked() {
+            return;
+        }
+        set_show_translation.set(true);
+        if let Some(revealed) = on_reveal_translation {
+            revealed.run(show_example.get_untracked())
+        }
+    };
+
+    // Reveal the example first, then the translation
+    let reveal_next = move || {
+        if !show_example.get_untracked() {
+            set_show_example.set(true);
+        } else {
+            reveal_translation();
+        }
+    };
+
+    // Grade the card once its translation is shown
+    let grade = move |known: bool| {
+        if let Some(grade) = on_grade
+            && show_translation.get_untracked()
+        {
+            grade.run(known)
+        }
+    };
+
+    let speak_word = source_word.clone();
+    let favorite_action = on_toggle_favorite.clone();
+    let mut bindings = vec![
+        KeyBinding::new(
+            &[" "],
+            "Space",
+            "Reveal example, then translation",
+            reveal_next,
+        ),
+        KeyBinding::new(&["s", "S"], "S", "Speak the word", move || {
+            speak(speak_word.clone(), source_lang)
+        }),
+        KeyBinding::new(&["f", "F"], "F", "Toggle favorite", move || {
+            favorite_action()
+        }),
+    ];
+    if on_grade.is_some() {
+        bindings.push(KeyBinding::new(
+            &["n", "N", "1"],
+            "N / 1",
+            "I didn't know it",
+            move || grade(false),
+        ));
+        bindings.push(KeyBinding::new(
+            &["y", "Y", "2"],
+            "Y / 2",
+            "I knew it",
+            move || grade(true),
+        ));
+    }
+    use_key_bindings(bindings);
+
+    // Swipe gestures, with the card following the finger while dragging.
+    // The card captures the pointer, so a drag ending off the card still
+    // ends here.
+    let card_ref = NodeRef::<leptos::html::Div>::new();
+    let drag_start = StoredValue::new(None::<(f64, f64, f64)>);
+    let (drag_offset, set_drag_offset) = signal((0.0, 0.0));
+
+    let handle_swipe = move |swipe: Swipe| match (swipe, on_grade) {
+        (Swipe::Up, _) => reveal_next(),
+        (Swipe::Left | Swipe::Right, Some(_)) if show_translation.get_untracked() => {
+            grade(swipe == Swipe::Right)
+        }
+        (Swipe::Left, _) => {
+            if let Some(next) = on_next {
+                next.run(())
+            }
+        }
+        (Swipe::Right, _) => {
+            if let Some(previous) = on_previous {
+                previous.run(())
+            }
+        }
+        (Swipe::Down, _) => {}
+    };
+
+    let on_pointer_down = move |ev: leptos::ev::PointerEvent| {
+        if ev.pointer_type() == "touch" {
+            if let Some(card) = card_ref.get_untracked() {
+                let _ = card.set_pointer_capture(ev.pointer_id());
+            }
+            drag_start.set_value(Some((
+                ev.client_x() as f64,
+                ev.client_y() as f64,
+                ev.time_stamp(),
+            )));
+        }
+    };
+
+    let on_pointer_move = move |ev: leptos::ev::PointerEvent| {
+        if let Some((x, y, _)) = drag_start.get_value() {
+            set_drag_offset.set((ev.client_x() as f64 - x, ev.client_y() as f64 - y));
+        }
+    };
+
+    let on_pointer_up = move |ev: leptos::ev::PointerEvent| {
+        if let Some((x, y, started)) = drag_start.get_value() {
+            drag_start.set_value(None);
+            set_drag_offset.set((0.0, 0.0));
+            let (dx, dy) = (ev.client_x() as f64 - x, ev.client_y() as f64 - y);
+            if let Some(swipe) = classify_swipe(dx, dy, ev.time_stamp() - started) {
+                handle_swipe(swipe);
+            }
+        }
+    };
+
+    let on_pointer_cancel = move |_| {
+        drag_start.set_value(None);
+        set_drag_offset.set((0.0, 0.0));
+    };
+
+    // Only follow upward drags vertically, since swiping down does nothing
+    let card_style = move || {
+        let (dx, dy) = drag_offset.get();
+        format!(
+            "transform: translate({}px, {}px) rotate({}deg);",
+            dx,
+            dy.min(0.0),
+            dx / 20.0
+        )
+    };
+
+    let card_class = move || {
+        if drag_offset.get() == (0.0, 0.0) {
+            "vocabulary-card"
+        } else {
+            "vocabulary-card dragging"
+        }
+    };
+
     let source_word_clone = source_word.clone();
+    let source_word_highlight = source_word.clone();
     let source_example_clone = source_example.clone();
+    let source_example_ipa = source_example.clone();
     let target_word_clone = target_word.clone();
     let target_example_clone = target_example.clone();
 
     view! {
-        <div class="vocabulary-card">
+        <div
+            node_ref=card_ref
+            class=card_class
+            style=card_style
+            on:pointerdown=on_pointer_down
+            on:pointermove=on_pointer_move
+            on:pointerup=on_pointer_up
+            on:pointercancel=on_pointer_cancel
+        >
             <div style="display: flex; justify-content: space-between; align-items: center; margin-bottom: 1rem;">
                 <div class="card-progress">
                     {move || {
@@ -91,7 +248,11 @@ where
                 </div>
             </div>
             <div class="card-main">
-                <h2 class="card-word">{source_word}</h2>
+                <h2 class="card-word">{source_word.clone()}</h2>
+                {(direction.source_language() == Language::Spanish).then(|| view! {
+                    <SyllableBreakdown text=source_word.clone()/>
+                    <IpaTranscription text=source_word/>
+                })}
             </div>
 
             {move || (!show_example.get()).then(|| view! {
@@ -107,7 +268,13 @@ where
                 let example_audio = source_example_clone.clone();
                 view! {
                     <div class="card-example" style="display: flex; align-items: center; gap: 0.5rem; margin: 0;">
-                        <p style="margin: 0; flex: 1;">{source_example.clone()}</p>
+                        <p style="margin: 0; flex: 1;">
+                            <HighlightedExample
+                                headword=source_word_highlight.clone()
+                                example=source_example.clone()
+                                language=direction.source_language()
+                            />
+                        </p>
                         <button
                             class="audio-button-small"
                             on:click=move |_| speak(example_audio.clone(), source_lang)
@@ -115,13 +282,16 @@ where
                             "🔉"
                         </button>
                     </div>
+                    {(direction.source_language() == Language::Spanish).then(|| view! {
+                        <IpaTranscription text=source_example_ipa.clone()/>
+                    })}
                 }
             })}
 
             {move || (!show_translation.get()).then(|| view! {
                 <button
                     class="reveal-button translation-button"
-                    on:click=move |_| set_show_translation.set(true)
+                    on:click=move |_| reveal_translation()
                 >
                     "Show Translation"
                 </button>
@@ -141,8 +311,18 @@ where
                                 "🔉"
                             </button>
                         </div>
+                        {(direction.target_language() == Language::Spanish).then(|| view! {
+                            <SyllableBreakdown text=target_word.clone()/>
+                            <IpaTranscription text=target_word.clone()/>
+                        })}
                         <div style="display: flex; align-items: center; gap: 0.5rem; margin-top: 1.0rem;">
-                            <p class="translation-example" style="margin: 0; flex: 1;">{target_example.clone()}</p>
+                            <p class="translation-example" style="margin: 0; flex: 1;">
+                                <HighlightedExample
+                                    headword=target_word.clone()
+                                    example=target_example.clone()
+                                    language=direction.target_language()
+                                />
+                            </p>
                             <button
                                 class="audio-button-small"
                                 on:click=move |_| speak(example_audio.clone(), target_lang)
@@ -150,9 +330,19 @@ where
                                 "🔉"
                             </button>
                         </div>
+                        {(direction.target_language() == Language::Spanish).then(|| view! {
+                            <IpaTranscription text=target_example.clone()/>
+                        })}
                     </div>
                 }
             })}
+
+            {move || (show_translation.get() && on_grade.is_some()).then(|| view! {
+                <div class="grade-buttons">
+                    <button class="grade-button unknown" on:click=move |_| grade(false)>"✗ Didn't know"</button>
+                    <button class="grade-button known" on:click=move |_| grade(true)>"✓ Knew it"</button>
+                </div>
+            })}
         </div>
     }
 }
@@ -0,0 +1,245 @@
+//! Why a Spanish word does or doesn't carry a written accent (tilde).
+//!
+//! The general rules depend on where the stress falls and how the word ends;
+//! hiatus and diacritic tildes override them.
+
+use crate::core::syllables::{Stress, Syllables, syllabify};
+use crate::core::text::fold_accent;
+
+/// Words told apart from a homograph only by the tilde: (with tilde, meaning,
+/// without tilde, meaning)
+const DIACRITICS: [(&str, &str, &str, &str); 15] = [
+    ("tú", "you", "tu", "your"),
+    ("él", "he", "el", "the"),
+    ("mí", "me", "mi", "my"),
+    ("sí", "yes", "si", "if"),
+    ("té", "tea", "te", "you (object)"),
+    ("dé", "give", "de", "of"),
+    ("sé", "I know", "se", "oneself"),
+    ("más", "more", "mas", "but"),
+    ("aún", "still", "aun", "even"),
+    ("qué", "what?", "que", "that"),
+    ("cómo", "how?", "como", "like"),
+    ("dónde", "where?", "donde", "where"),
+    ("cuándo", "when?", "cuando", "when"),
+    ("quién", "who?", "quien", "who"),
+    ("cuál", "which?", "cual", "which"),
+];
+
+/// The rule deciding whether a word is written with a tilde
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AccentRule {
+    /// One syllable words take no tilde
+    Monosyllable,
+    /// Follows the general rule for its stress position
+    General(Stress),
+    /// A stressed í or ú next to a, e or o keeps its own syllable
+    Hiatus,
+    /// Told apart from a homograph: (with tilde, meaning, without, meaning)
+    Diacritic(&'static str, &'static str, &'static str, &'static str),
+}
+
+/// How a word is accented and why
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Accentuation {
+    pub syllables: Syllables,
+    pub rule: AccentRule,
+    /// Index of the syllable carrying the tilde, if any
+    pub tilde: Option<usize>,
+    /// Whether the word ends in a vowel, `n` or `s`
+    pub ends_in_vowel_n_s: bool,
+}
+
+impl Accentuation {
+    /// One sentence explaining why the word is written the way it is
+    pub fn explanation(&self) -> String {
+        let ending = if self.ends_in_vowel_n_s {
+            "ends in a vowel, n or s"
+        } else {
+            "doesn't end in a vowel, n or s"
+        };
+        match self.rule {
+            AccentRule::Monosyllable => "One syllable: no tilde.".to_string(),
+            AccentRule::General(Stress::Aguda) if self.tilde.is_some() => format!(
+                "Aguda (stressed on the last syllable) that {}: it takes a tilde.",
+                ending
+            ),
+            AccentRule::General(Stress::Aguda) => format!(
+                "Aguda (stressed on the last syllable) that {}: no tilde.",
+                ending
+            ),
+            AccentRule::General(Stress::Llana) if self.tilde.is_some() => format!(
+                "Llana (stressed on the second to last syllable) that {}: it takes a tilde.",
+                ending
+            ),
+            AccentRule::General(Stress::Llana) => format!(
+                "Llana (stressed on the second to last syllable) that {}: no tilde.",
+                ending
+            ),
+            AccentRule::General(Stress::Esdrujula) => {
+                "Esdrújula (stressed on the third to last syllable): always a tilde.".to_string()
+            }
+            AccentRule::General(Stress::Sobresdrujula) => {
+                "Sobresdrújula (stressed before the third to last syllable): always a tilde."
+                    .to_string()
+            }
+            AccentRule::Hiatus => {
+                "Hiatus: the stressed í or ú next to a, e or o is its own syllable, so it takes a tilde whatever the ending."
+                    .to_string()
+            }
+            AccentRule::Diacritic(with, with_meaning, without, without_meaning) => format!(
+                "Diacritic tilde: {} ({}) is written with a tilde to tell it from {} ({}).",
+                with, with_meaning, without, without_meaning
+            ),
+        }
+    }
+}
+
+/// Analyse a single word; `None` if it can't be split into syllables
+pub fn accentuation(word: &str) -> Option<Accentuation> {
+    let syllables = syllabify(word)?;
+    let lower: Vec<char> = word.chars().flat_map(char::to_lowercase).collect();
+    let lower_word: String = lower.iter().collect();
+    let tilde = syllables
+        .syllables
+        .iter()
+        .position(|s| s.to_lowercase().chars().any(has_tilde));
+    let ends_in_vowel_n_s = matches!(
+        lower.last().copied().map(fold_accent),
+        Some('a' | 'e' | 'i' | 'o' | 'u' | 'n' | 's')
+    );
+
+    let diacritic = DIACRITICS
+        .iter()
+        .find(|(with, _, without, _)| lower_word == *with || lower_word == *without);
+    let rule = if let Some(&(with, with_meaning, without, without_meaning)) = diacritic {
+        AccentRule::Diacritic(with, with_meaning, without, without_meaning)
+    } else if syllables.syllables.len() == 1 {
+        AccentRule::Monosyllable
+    } else if is_hiatus(&lower) {
+        AccentRule::Hiatus
+    } else {
+        AccentRule::General(syllables.stress())
+    };
+
+    Some(Accentuation {
+        syllables,
+        rule,
+        tilde,
+        ends_in_vowel_n_s,
+    })
+}
+
+/// Remove the tildes from a word, keeping `ü` and `ñ`
+pub fn strip_tildes(word: &str) -> String {
+    word.chars()
+        .map(|c| match c {
+            'á' => 'a',
+            'é' => 'e',
+            'í' => 'i',
+            'ó' => 'o',
+            'ú' => 'u',
+            'Á' => 'A',
+            'É' => 'E',
+            'Í' => 'I',
+            'Ó' => 'O',
+            'Ú' => 'U',
+            _ => c,
+        })
+        .collect()
+}
+
+fn has_tilde(c: char) -> bool {
+    "áéíóú".contains(c)
+}
+
+/// A written í or ú beside a, e or o, possibly across a silent h (`búho`)
+fn is_hiatus(lower: &[char]) -> bool {
+    let strong = |c: Option<&char>| matches!(c, Some('a' | 'e' | 'o'));
+    lower.iter().enumerate().any(|(i, c)| {
+        if !matches!(c, 'í' | 'ú') {
+            return false;
+        }
+        let before = match i.checked_sub(1).map(|p| lower[p]) {
+            Some('h') => i.checked_sub(2).and_then(|p| lower.get(p)),
+            _ => i.checked_sub(1).and_then(|p| lower.get(p)),
+        };
+        let after = match lower.get(i + 1) {
+            Some('h') => lower.get(i + 2),
+            other => other,
+        };
+        strong(before) || strong(after)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(word: &str) -> AccentRule {
+        accentuation(word).unwrap().rule
+    }
+
+    #[test]
+    fn general_rules_follow_stress_and_ending() {
+        let cafe = accentuation("café").unwrap();
+        assert_eq!(cafe.rule, AccentRule::General(Stress::Aguda));
+        assert_eq!(cafe.tilde, Some(1));
+        assert!(cafe.ends_in_vowel_n_s);
+
+        let reloj = accentuation("reloj").unwrap();
+        assert_eq!(reloj.tilde, None);
+        assert!(!reloj.ends_in_vowel_n_s);
+
+        let arbol = accentuation("árbol").unwrap();
+        assert_eq!(arbol.rule, AccentRule::General(Stress::Llana));
+        assert_eq!(arbol.tilde, Some(0));
+
+        assert_eq!(accentuation("casa").unwrap().tilde, None);
+        assert_eq!(rule("música"), AccentRule::General(Stress::Esdrujula));
+    }
+
+    #[test]
+    fn hiatus_overrides_the_ending() {
+        assert_eq!(rule("día"), AccentRule::Hiatus);
+        assert_eq!(rule("país"), AccentRule::Hiatus);
+        assert_eq!(rule("búho"), AccentRule::Hiatus);
+        assert_eq!(
+            rule("canción"),
+            AccentRule::General(Stress::Aguda),
+            "a tilde on a strong vowel is not a hiatus"
+        );
+    }
+
+    #[test]
+    fn diacritics_and_monosyllables() {
+        assert!(matches!(
+            rule("tú"),
+            AccentRule::Diacritic("tú", _, "tu", _)
+        ));
+        assert!(matches!(
+            rule("tu"),
+            AccentRule::Diacritic("tú", _, "tu", _)
+        ));
+        assert!(matches!(rule("Qué"), AccentRule::Diacritic("qué", ..)));
+        assert_eq!(rule("sol"), AccentRule::Monosyllable);
+        assert_eq!(rule("fue"), AccentRule::Monosyllable);
+    }
+
+    #[test]
+    fn explanations_match_the_spelling() {
+        assert!(
+            accentuation("árbol")
+                .unwrap()
+                .explanation()
+                .contains("it takes a tilde")
+        );
+        assert!(
+            accentuation("examen")
+                .unwrap()
+                .explanation()
+                .contains("no tilde")
+        );
+        assert_eq!(strip_tildes("Árbol canción"), "Arbol cancion");
+    }
+}
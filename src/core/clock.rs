@@ -0,0 +1,104 @@
+//! Wall-clock time and local calendar days, from the browser's `Date`.
+
+#[cfg(target_arch = "wasm32")]
+mod js {
+    use wasm_bindgen::prelude::*;
+
+    #[wasm_bindgen]
+    extern "C" {
+        pub type Date;
+
+        #[wasm_bindgen(constructor)]
+        pub fn new() -> Date;
+
+        #[wasm_bindgen(static_method_of = Date)]
+        pub fn now() -> f64;
+
+        #[wasm_bindgen(method, js_name = getTimezoneOffset)]
+        pub fn timezone_offset(this: &Date) -> f64;
+    }
+}
+
+const MS_PER_DAY: f64 = 86_400_000.0;
+
+/// Milliseconds since the Unix epoch
+pub fn now_ms() -> f64 {
+    #[cfg(target_arch = "wasm32")]
+    let now = js::Date::now();
+    #[cfg(not(target_arch = "wasm32"))]
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_millis() as f64)
+        .unwrap_or(0.0);
+    now
+}
+
+/// How far local time is ahead of UTC, in milliseconds
+fn utc_offset_ms() -> f64 {
+    #[cfg(target_arch = "wasm32")]
+    let offset = -js::Date::new().timezone_offset() * 60_000.0;
+    // Off the web, days are counted in UTC
+    #[cfg(not(target_arch = "wasm32"))]
+    let offset = 0.0;
+    offset
+}
+
+/// Local calendar day of a moment, in days since 1970-01-01
+pub fn local_day(ms: f64) -> i64 {
+    ((ms + utc_offset_ms()) / MS_PER_DAY).floor() as i64
+}
+
+pub fn today() -> i64 {
+    local_day(now_ms())
+}
+
+/// Moment a local day reaches `hour` o'clock, in milliseconds since the epoch
+pub fn local_time_ms(day: i64, hour: u32) -> f64 {
+    day as f64 * MS_PER_DAY + f64::from(hour) * 3_600_000.0 - utc_offset_ms()
+}
+
+/// Day of the week, counting from Monday as 0
+pub fn weekday(day: i64) -> u32 {
+    // 1970-01-01 was a Thursday
+    (day + 3).rem_euclid(7) as u32
+}
+
+/// Year, month (1–12) and day of the month of a day since 1970-01-01
+pub fn civil_date(day: i64) -> (i64, u32, u32) {
+    // Howard Hinnant's `civil_from_days`, with years starting in March
+    let z = day + 719_468;
+    let era = z.div_euclid(146_097);
+    let day_of_era = z.rem_euclid(146_097);
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day_of_month = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month as u32, day_of_month as u32)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn days_map_to_calendar_dates() {
+        assert_eq!(civil_date(0), (1970, 1, 1));
+        assert_eq!(civil_date(19_723), (2024, 1, 1));
+        assert_eq!(civil_date(19_782), (2024, 2, 29));
+        assert_eq!(civil_date(-1), (1969, 12, 31));
+    }
+
+    #[test]
+    fn weeks_start_on_monday() {
+        assert_eq!(weekday(0), 3);
+        // 2024-01-01 was a Monday
+        assert_eq!(weekday(19_723), 0);
+    }
+}
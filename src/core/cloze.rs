@@ -0,0 +1,243 @@
+//! Fill-in-the-blank exercises built from card example sentences.
+
+use crate::core::headword::locate_headword;
+use crate::core::language::Language;
+use crate::core::text::normalize;
+use crate::data::VocabularyCard;
+
+/// An example sentence with its headword blanked out
+#[derive(Debug, Clone, PartialEq)]
+pub struct Cloze {
+    /// Global card ID the sentence comes from
+    pub card_id: u32,
+    /// Sentence text before the blank
+    pub before: String,
+    /// The blanked words, as written in the sentence
+    pub answer: String,
+    /// Sentence text after the blank
+    pub after: String,
+    /// The example in the other language, shown as a hint
+    pub hint: String,
+}
+
+impl Cloze {
+    /// Whether a typed or selected fill is right, ignoring case and accents
+    pub fn check(&self, fill: &str) -> bool {
+        normalize(fill) == normalize(&self.answer)
+    }
+}
+
+/// Blank the headword of `target` in its example, with the `source` example
+/// as a hint. Returns `None` when the headword can't be located.
+pub fn generate_cloze(
+    target: &VocabularyCard,
+    source: &VocabularyCard,
+    language: Language,
+) -> Option<Cloze> {
+    let range = locate_headword(&target.word, &target.example, language)?;
+    Some(Cloze {
+        card_id: target.id,
+        before: target.example[..range.start].to_string(),
+        answer: target.example[range.clone()].to_string(),
+        after: target.example[range.end..].to_string(),
+        hint: source.example.clone(),
+    })
+}
+
+/// Answer choices for the cloze at `index`: its answer and up to `count - 1`
+/// answers of other clozes, in alphabetical order
+pub fn cloze_choices(clozes: &[Cloze], index: usize, count: usize) -> Vec<String> {
+    let Some(cloze) = clozes.get(index) else {
+        return Vec::new();
+    };
+    let mut choices = vec![cloze.answer.clone()];
+    // Step through the other sentences so neighbours don't always appear together
+    let step = (clozes.len() / count.max(1)).max(1);
+    for offset in (1..clozes.len()).map(|i| (index + i * step) % clozes.len()) {
+        if choices.len() >= count {
+            break;
+        }
+        let answer = &clozes[offset].answer;
+        if !choices.iter().any(|c| normalize(c) == normalize(answer)) {
+            choices.push(answer.clone());
+        }
+    }
+    choices.sort_by_key(|c| normalize(c));
+    choices
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{STAGE_COUNT, load_vocabulary_stage};
+
+    fn card(word: &str, example: &str) -> VocabularyCard {
+        VocabularyCard {
+            id: 1,
+            word: word.to_string(),
+            example: example.to_string(),
+            alternatives: Vec::new(),
+        }
+    }
+
+    fn answer(word: &str, example: &str, language: Language) -> Option<String> {
+        generate_cloze(&card(word, example), &card("", ""), language).map(|c| c.answer)
+    }
+
+    /// Cards whose example uses a different word or a contraction (`I'm`, `can't`)
+    const ENGLISH_GAPS: [u32; 9] = [34, 35, 80, 82, 169, 185, 196, 343, 407];
+
+    /// Cards whose headword can't be located, with a readable report
+    fn unlocated(language: Language) -> (Vec<u32>, String) {
+        let code = match language {
+            Language::Spanish => "es",
+            Language::English => "en",
+        };
+        let missing: Vec<VocabularyCard> = (1..=STAGE_COUNT)
+            .flat_map(|stage| load_vocabulary_stage(stage, code).unwrap())
+            .filter(|c| generate_cloze(c, c, language).is_none())
+            .collect();
+        let report = missing
+            .iter()
+            .map(|c| format!("{} {} | {}", c.id, c.word, c.example))
+            .collect::<Vec<_>>()
+            .join("\n");
+        (missing.iter().map(|c| c.id).collect(), report)
+    }
+
+    #[test]
+    fn articles_are_not_blanked() {
+        let cloze = generate_cloze(
+            &card("la casa", "La casa está cerca del parque"),
+            &card("the house", "The house is close to the park"),
+            Language::Spanish,
+        )
+        .unwrap();
+        assert_eq!(cloze.before, "La ");
+        assert_eq!(cloze.answer, "casa");
+        assert_eq!(cloze.after, " está cerca del parque");
+        assert_eq!(cloze.hint, "The house is close to the park");
+    }
+
+    #[test]
+    fn spanish_inflections_are_located() {
+        let es = Language::Spanish;
+        assert_eq!(
+            answer("la casa", "Tengo dos casas", es).as_deref(),
+            Some("casas")
+        );
+        assert_eq!(
+            answer("la luz", "Apaga las luces", es).as_deref(),
+            Some("luces")
+        );
+        assert_eq!(
+            answer("tener", "Tengo dos gatos", es).as_deref(),
+            Some("Tengo")
+        );
+        assert_eq!(
+            answer("poder", "No puedo ir hoy", es).as_deref(),
+            Some("puedo")
+        );
+        assert_eq!(
+            answer("pagar", "Ya pagué la cuenta", es).as_deref(),
+            Some("pagué")
+        );
+        assert_eq!(
+            answer("sentarse", "Siéntate aquí", es).as_deref(),
+            Some("Siéntate")
+        );
+        assert_eq!(
+            answer("malo/a", "Hace mal tiempo", es).as_deref(),
+            Some("mal")
+        );
+        assert_eq!(
+            answer("el/la amigo/a", "Mi amiga vive aquí", es).as_deref(),
+            Some("amiga")
+        );
+    }
+
+    #[test]
+    fn english_inflections_are_located() {
+        let en = Language::English;
+        assert_eq!(
+            answer("to buy", "I bought bread", en).as_deref(),
+            Some("bought")
+        );
+        assert_eq!(
+            answer("to stop", "The bus stopped", en).as_deref(),
+            Some("stopped")
+        );
+        assert_eq!(
+            answer("the leaf", "The leaves fall", en).as_deref(),
+            Some("leaves")
+        );
+        assert_eq!(
+            answer("fast/quick", "It is faster", en).as_deref(),
+            Some("faster")
+        );
+        assert_eq!(
+            answer("to get lost", "I got lost", en).as_deref(),
+            Some("got lost")
+        );
+        assert_eq!(
+            answer("to sit down", "Sit here", en).as_deref(),
+            Some("Sit")
+        );
+        assert_eq!(
+            answer("to teach", "My teacher teaches math", en).as_deref(),
+            Some("teaches")
+        );
+    }
+
+    #[test]
+    fn fills_ignore_case_and_accents() {
+        let cloze = generate_cloze(
+            &card("el árbol", "Ese árbol es muy alto"),
+            &card("the tree", "That tree is very tall"),
+            Language::Spanish,
+        )
+        .unwrap();
+        assert!(cloze.check("árbol"));
+        assert!(cloze.check(" Arbol "));
+        assert!(!cloze.check("árboles"));
+    }
+
+    #[test]
+    fn unlocatable_headwords_have_no_cloze() {
+        assert_eq!(answer("la casa", "Vivo aquí", Language::Spanish), None);
+    }
+
+    #[test]
+    fn choices_include_the_answer_once() {
+        let clozes: Vec<Cloze> = ["casa", "perro", "Casa", "libro", "mesa"]
+            .iter()
+            .map(|word| {
+                generate_cloze(&card(word, word), &card("", ""), Language::Spanish).unwrap()
+            })
+            .collect();
+        let choices = cloze_choices(&clozes, 0, 4);
+        assert_eq!(choices.len(), 4);
+        assert_eq!(choices.iter().filter(|c| normalize(c) == "casa").count(), 1);
+        assert!(cloze_choices(&clozes, 9, 4).is_empty());
+    }
+
+    #[test]
+    fn spanish_headwords_are_located_in_every_stage() {
+        let (missing, report) = unlocated(Language::Spanish);
+        assert!(
+            missing.is_empty(),
+            "unlocated Spanish headwords:\n{}",
+            report
+        );
+    }
+
+    #[test]
+    fn english_headwords_are_located_in_every_stage() {
+        let (missing, report) = unlocated(Language::English);
+        assert_eq!(
+            missing, ENGLISH_GAPS,
+            "unlocated English headwords:\n{}",
+            report
+        );
+    }
+}
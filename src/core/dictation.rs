@@ -0,0 +1,191 @@
+//! Word-by-word grading of dictated sentences.
+//!
+//! The typed answer is aligned against the sentence with the fewest edits,
+//! where a typo counts as one edit and a wholly different word as two (a
+//! missing word plus an extra one). Case, punctuation and the inverted marks
+//! `¿ ¡` are ignored; accents are part of the spelling.
+
+use crate::core::text::edit_distance;
+
+/// How a word of the sentence or the answer compares
+#[derive(Debug, Clone, PartialEq)]
+pub enum WordDiff {
+    Correct(String),
+    /// The word was typed, but misspelled
+    Misspelled {
+        expected: String,
+        typed: String,
+    },
+    /// A word of the sentence left out of the answer
+    Missing(String),
+    /// A word of the answer that isn't in the sentence
+    Extra(String),
+}
+
+/// A word as written, and the form it is compared by
+struct Word<'a> {
+    text: &'a str,
+    key: String,
+}
+
+fn words(text: &str) -> Vec<Word<'_>> {
+    text.split_whitespace()
+        .map(|text| Word {
+            text: text.trim_matches(|c: char| !c.is_alphanumeric()),
+            key: text
+                .chars()
+                .filter(|c| c.is_alphanumeric())
+                .flat_map(char::to_lowercase)
+                .collect(),
+        })
+        .filter(|word| !word.key.is_empty())
+        .collect()
+}
+
+/// Whether `typed` reads as an attempt at `expected`
+fn is_typo(expected: &str, typed: &str) -> bool {
+    let allowed = (expected.chars().count() / 3).max(1);
+    edit_distance(expected, typed) <= allowed
+}
+
+/// Align `typed` against `sentence` word by word, in sentence order
+pub fn align_words(sentence: &str, typed: &str) -> Vec<WordDiff> {
+    let expected = words(sentence);
+    let typed = words(typed);
+
+    // costs[i][j]: edits to turn the first j typed words into the first i expected ones
+    let mut costs = vec![vec![0usize; typed.len() + 1]; expected.len() + 1];
+    for (i, row) in costs.iter_mut().enumerate() {
+        row[0] = i;
+    }
+    for (j, cell) in costs[0].iter_mut().enumerate() {
+        *cell = j;
+    }
+    let substitution = |i: usize, j: usize| {
+        let (e, t) = (&expected[i - 1].key, &typed[j - 1].key);
+        if e == t {
+            Some(0)
+        } else if is_typo(e, t) {
+            Some(1)
+        } else {
+            None
+        }
+    };
+    for i in 1..=expected.len() {
+        for j in 1..=typed.len() {
+            let mut best = (costs[i - 1][j] + 1).min(costs[i][j - 1] + 1);
+            if let Some(cost) = substitution(i, j) {
+                best = best.min(costs[i - 1][j - 1] + cost);
+            }
+            costs[i][j] = best;
+        }
+    }
+
+    let mut diffs = Vec::new();
+    let (mut i, mut j) = (expected.len(), typed.len());
+    while i > 0 || j > 0 {
+        if i > 0
+            && j > 0
+            && substitution(i, j).is_some_and(|cost| costs[i - 1][j - 1] + cost == costs[i][j])
+        {
+            let (e, t) = (&expected[i - 1], &typed[j - 1]);
+            diffs.push(if e.key == t.key {
+                WordDiff::Correct(e.text.to_string())
+            } else {
+                WordDiff::Misspelled {
+                    expected: e.text.to_string(),
+                    typed: t.text.to_string(),
+                }
+            });
+            i -= 1;
+            j -= 1;
+        } else if j > 0 && costs[i][j - 1] + 1 == costs[i][j] {
+            // Taken first when walking back, so a missing word is listed before
+            // the extra one typed in its place
+            diffs.push(WordDiff::Extra(typed[j - 1].text.to_string()));
+            j -= 1;
+        } else {
+            diffs.push(WordDiff::Missing(expected[i - 1].text.to_string()));
+            i -= 1;
+        }
+    }
+    diffs.reverse();
+    diffs
+}
+
+/// Whether every word of the sentence was typed right, and nothing else
+pub fn is_perfect(diffs: &[WordDiff]) -> bool {
+    diffs.iter().all(|d| matches!(d, WordDiff::Correct(_)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn correct(word: &str) -> WordDiff {
+        WordDiff::Correct(word.to_string())
+    }
+
+    #[test]
+    fn punctuation_and_case_are_ignored() {
+        let diffs = align_words("¿Dónde está la estación?", "donde Está la estación");
+        assert_eq!(
+            diffs[0],
+            WordDiff::Misspelled {
+                expected: "Dónde".to_string(),
+                typed: "donde".to_string(),
+            }
+        );
+        assert_eq!(
+            &diffs[1..],
+            [correct("está"), correct("la"), correct("estación")]
+        );
+
+        let diffs = align_words("¡Hola, amigo!", "hola amigo");
+        assert!(is_perfect(&diffs));
+    }
+
+    #[test]
+    fn missing_and_extra_words() {
+        assert_eq!(
+            align_words("Quiero beber el agua fría", "quiero beber agua muy fría"),
+            [
+                correct("Quiero"),
+                correct("beber"),
+                WordDiff::Missing("el".to_string()),
+                correct("agua"),
+                WordDiff::Extra("muy".to_string()),
+                correct("fría"),
+            ]
+        );
+    }
+
+    #[test]
+    fn typos_are_misspellings_but_other_words_are_not() {
+        assert_eq!(
+            align_words("La casa está cerca", "la caza está lejos"),
+            [
+                correct("La"),
+                WordDiff::Misspelled {
+                    expected: "casa".to_string(),
+                    typed: "caza".to_string(),
+                },
+                correct("está"),
+                WordDiff::Missing("cerca".to_string()),
+                WordDiff::Extra("lejos".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn an_empty_answer_misses_everything() {
+        assert_eq!(
+            align_words("Hoy es lunes", ""),
+            [
+                WordDiff::Missing("Hoy".to_string()),
+                WordDiff::Missing("es".to_string()),
+                WordDiff::Missing("lunes".to_string()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,136 @@
+//! A local log of study events — cards viewed, revealed, answered and
+//! graded — that the statistics and daily goals are computed from.
+
+use crate::core::{clock, storage};
+use leptos::ev;
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// `localStorage` key holding the event log as JSON
+const STORAGE_KEY: &str = "vamos.events";
+
+/// Events kept; the oldest are dropped past this, to keep storage small
+const MAX_EVENTS: usize = 10_000;
+
+/// Least time between two saves of the log, which grows to hundreds of
+/// kilobytes of JSON; events in between are saved with the next one, or
+/// when the page is hidden
+const SAVE_INTERVAL_MS: f64 = 10_000.0;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EventKind {
+    /// A card was shown
+    View,
+    /// A card's translation was revealed
+    Reveal,
+    /// A quiz question about the card was answered
+    Answer { correct: bool },
+    /// The card was self-graded as known or not
+    Grade { known: bool },
+}
+
+impl EventKind {
+    /// Whether the card was recalled, for answers and grades
+    pub fn outcome(&self) -> Option<bool> {
+        match self {
+            EventKind::Answer { correct } => Some(*correct),
+            EventKind::Grade { known } => Some(*known),
+            EventKind::View | EventKind::Reveal => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct StudyEvent {
+    /// Milliseconds since the Unix epoch
+    pub at: f64,
+    /// Local calendar day, in days since 1970-01-01
+    pub day: i64,
+    /// Global card ID
+    pub card_id: u32,
+    pub kind: EventKind,
+}
+
+/// Global context for the study event log, saved at most every
+/// `SAVE_INTERVAL_MS`
+#[derive(Clone, Copy)]
+pub struct EventLogContext {
+    pub events: RwSignal<Vec<StudyEvent>>,
+    /// When the log was last saved, and whether it changed since
+    last_save: StoredValue<f64>,
+    unsaved: StoredValue<bool>,
+}
+
+impl EventLogContext {
+    pub fn new() -> Self {
+        let saved = storage::load(STORAGE_KEY)
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default();
+        Self {
+            events: RwSignal::new(saved),
+            last_save: StoredValue::new(f64::NEG_INFINITY),
+            unsaved: StoredValue::new(false),
+        }
+    }
+
+    pub fn record(&self, card_id: u32, kind: EventKind) {
+        let at = clock::now_ms();
+        self.events.update(|events| {
+            events.push(StudyEvent {
+                at,
+                day: clock::local_day(at),
+                card_id,
+                kind,
+            });
+            let excess = events.len().saturating_sub(MAX_EVENTS);
+            events.drain(..excess);
+        });
+        self.unsaved.set_value(true);
+        if at - self.last_save.get_value() >= SAVE_INTERVAL_MS {
+            self.flush();
+        }
+    }
+
+    /// Save the events not saved yet
+    pub fn flush(&self) {
+        if !self.unsaved.get_value() {
+            return;
+        }
+        if let Ok(json) = serde_json::to_string(&*self.events.read_untracked()) {
+            storage::save(STORAGE_KEY, &json);
+        }
+        self.unsaved.set_value(false);
+        self.last_save.set_value(clock::now_ms());
+    }
+}
+
+/// Save the event log when the page is hidden or closed, so the events held
+/// back between saves aren't lost
+pub fn use_event_log_flush() {
+    let events_ctx = expect_context::<EventLogContext>();
+    // The page may never be shown again after these, e.g. on mobile
+    let hidden = window_event_listener(ev::visibilitychange, move |_| events_ctx.flush());
+    let closed = window_event_listener(ev::pagehide, move |_| events_ctx.flush());
+    on_cleanup(move || {
+        hidden.remove();
+        closed.remove();
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn saves_are_held_back_between_intervals() {
+        let log = EventLogContext::new();
+        log.record(1, EventKind::View);
+        assert!(!log.unsaved.get_value());
+        log.record(1, EventKind::Reveal);
+        assert!(log.unsaved.get_value());
+        log.flush();
+        assert!(!log.unsaved.get_value());
+        assert_eq!(log.events.read_untracked().len(), 2);
+    }
+}
@@ -33,6 +33,12 @@ impl FavoritesContext {
         self.favorites.read().iter().copied().collect()
     }
 
+    /// Add every given (stage, card_id) pair, keeping existing favorites
+    pub fn merge(&self, cards: &[(u32, u32)]) {
+        self.favorites
+            .update(|favs| favs.extend(cards.iter().copied()));
+    }
+
     #[allow(dead_code)]
     pub fn remove(&self, stage: u32, card_id: u32) {
         self.favorites.update(|favs| {
@@ -0,0 +1,310 @@
+//! Grammatical gender of Spanish nouns and the rules of thumb behind it.
+//!
+//! The gender comes from the article in the vocabulary data; the rules only
+//! predict it, so a noun whose gender contradicts its rule is an exception
+//! worth pointing out (`el día`, `la mano`).
+
+use crate::core::inflection::plural;
+use crate::core::storage;
+use crate::data::VocabularyCard;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Feminine nouns starting with a stressed `a`, which take `el` and `un`
+const STRESSED_A_FEMININE: [&str; 13] = [
+    "agua", "alma", "águila", "área", "arma", "aula", "ave", "hacha", "hambre", "hada", "ala",
+    "ancla", "arpa",
+];
+
+/// Masculine nouns from Greek ending in -ma
+const GREEK_MA: [&str; 14] = [
+    "problema", "tema", "sistema", "idioma", "clima", "programa", "poema", "drama", "esquema",
+    "dilema", "síntoma", "diploma", "fantasma", "pijama",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Gender {
+    Masculine,
+    Feminine,
+}
+
+/// Which article is asked for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ArticleForm {
+    /// el / la
+    Definite,
+    /// un / una
+    Indefinite,
+    /// los / las
+    Plural,
+}
+
+impl ArticleForm {
+    pub const ALL: [ArticleForm; 3] = [
+        ArticleForm::Definite,
+        ArticleForm::Indefinite,
+        ArticleForm::Plural,
+    ];
+
+    /// The masculine and feminine articles, in that order
+    pub fn choices(&self) -> [&'static str; 2] {
+        match self {
+            ArticleForm::Definite => ["el", "la"],
+            ArticleForm::Indefinite => ["un", "una"],
+            ArticleForm::Plural => ["los", "las"],
+        }
+    }
+}
+
+/// Rule of thumb predicting a noun's gender
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum GenderRule {
+    StressedA,
+    GreekMa,
+    Ion,
+    Dad,
+    Umbre,
+    Aje,
+    Or,
+    EndsInO,
+    EndsInA,
+    NoRule,
+}
+
+impl GenderRule {
+    pub fn name(&self) -> &'static str {
+        match self {
+            GenderRule::StressedA => "stressed a- takes el",
+            GenderRule::GreekMa => "-ma from Greek → masculine",
+            GenderRule::Ion => "-ión → feminine",
+            GenderRule::Dad => "-dad, -tad, -tud → feminine",
+            GenderRule::Umbre => "-umbre → feminine",
+            GenderRule::Aje => "-aje → masculine",
+            GenderRule::Or => "-or → masculine",
+            GenderRule::EndsInO => "-o → masculine",
+            GenderRule::EndsInA => "-a → feminine",
+            GenderRule::NoRule => "other endings",
+        }
+    }
+
+    /// The gender the rule predicts, if it predicts one
+    pub fn predicts(&self) -> Option<Gender> {
+        match self {
+            GenderRule::GreekMa | GenderRule::Aje | GenderRule::Or | GenderRule::EndsInO => {
+                Some(Gender::Masculine)
+            }
+            GenderRule::StressedA
+            | GenderRule::Ion
+            | GenderRule::Dad
+            | GenderRule::Umbre
+            | GenderRule::EndsInA => Some(Gender::Feminine),
+            GenderRule::NoRule => None,
+        }
+    }
+}
+
+/// A noun from the vocabulary, without its article
+#[derive(Debug, Clone, PartialEq)]
+pub struct Noun {
+    pub card_id: u32,
+    pub word: String,
+    pub gender: Gender,
+}
+
+impl Noun {
+    /// The noun of an `el …` or `la …` card; `None` for other cards and for
+    /// nouns of both genders (`el/la amigo/a`)
+    pub fn from_card(card: &VocabularyCard) -> Option<Noun> {
+        let (article, word) = card.word.split_once(' ')?;
+        if word.contains(['/', '(']) {
+            return None;
+        }
+        let gender = match article {
+            "la" => Gender::Feminine,
+            "el" if STRESSED_A_FEMININE.contains(&word) => Gender::Feminine,
+            "el" => Gender::Masculine,
+            _ => return None,
+        };
+        Some(Noun {
+            card_id: card.id,
+            word: word.to_string(),
+            gender,
+        })
+    }
+
+    pub fn rule(&self) -> GenderRule {
+        let word = self.word.as_str();
+        if STRESSED_A_FEMININE.contains(&word) {
+            GenderRule::StressedA
+        } else if GREEK_MA.contains(&word) {
+            GenderRule::GreekMa
+        } else if word.ends_with("ión") {
+            GenderRule::Ion
+        } else if ["dad", "tad", "tud"].iter().any(|end| word.ends_with(end)) {
+            GenderRule::Dad
+        } else if word.ends_with("umbre") {
+            GenderRule::Umbre
+        } else if word.ends_with("aje") {
+            GenderRule::Aje
+        } else if word.ends_with("or") {
+            GenderRule::Or
+        } else if word.ends_with('o') {
+            GenderRule::EndsInO
+        } else if word.ends_with(['a', 'á']) {
+            GenderRule::EndsInA
+        } else {
+            GenderRule::NoRule
+        }
+    }
+
+    /// Whether the noun goes against its rule (`el día`, `la mano`)
+    pub fn is_exception(&self) -> bool {
+        self.rule()
+            .predicts()
+            .is_some_and(|gender| gender != self.gender)
+    }
+
+    /// The right article for the form; feminine nouns with a stressed `a`
+    /// take `el` and `un` in the singular
+    pub fn article(&self, form: ArticleForm) -> &'static str {
+        let [masculine, feminine] = form.choices();
+        let singular_a = self.rule() == GenderRule::StressedA && form != ArticleForm::Plural;
+        match self.gender {
+            Gender::Feminine if !singular_a => feminine,
+            _ => masculine,
+        }
+    }
+
+    /// The noun as it follows the article
+    pub fn form(&self, form: ArticleForm) -> String {
+        match form {
+            ArticleForm::Plural => plural(&self.word),
+            _ => self.word.clone(),
+        }
+    }
+
+    /// Why the noun takes the article it does
+    pub fn hint(&self) -> String {
+        let gender = match self.gender {
+            Gender::Masculine => "masculine",
+            Gender::Feminine => "feminine",
+        };
+        let rule = self.rule();
+        match rule {
+            GenderRule::StressedA => format!(
+                "{} is feminine, but starts with a stressed a, so it takes el and un in the singular (el {}, las {}).",
+                self.word,
+                self.word,
+                plural(&self.word)
+            ),
+            GenderRule::NoRule => format!(
+                "No reliable rule for this ending: {} is {}, learn it with its article.",
+                self.word, gender
+            ),
+            _ if self.is_exception() => {
+                format!("Exception to {}: {} is {}.", rule.name(), self.word, gender)
+            }
+            _ => format!("{}: {} is {}.", rule.name(), self.word, gender),
+        }
+    }
+}
+
+/// `localStorage` key holding the drill statistics as JSON
+const STATS_KEY: &str = "vamos.gender-stats";
+
+/// Answers and mistakes per rule
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct RuleStats {
+    pub answers: u32,
+    pub mistakes: u32,
+}
+
+/// Drill results per rule, kept across visits
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct GenderStats(pub BTreeMap<GenderRule, RuleStats>);
+
+impl GenderStats {
+    pub fn load() -> Self {
+        storage::load(STATS_KEY)
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn record(&mut self, rule: GenderRule, correct: bool) {
+        let stats = self.0.entry(rule).or_default();
+        stats.answers += 1;
+        if !correct {
+            stats.mistakes += 1;
+        }
+        if let Ok(json) = serde_json::to_string(self) {
+            storage::save(STATS_KEY, &json);
+        }
+    }
+
+    /// Rules with mistakes, most often broken first
+    pub fn most_broken(&self) -> Vec<(GenderRule, RuleStats)> {
+        let mut broken: Vec<_> = self
+            .0
+            .iter()
+            .filter(|(_, stats)| stats.mistakes > 0)
+            .map(|(rule, stats)| (*rule, *stats))
+            .collect();
+        broken.sort_by_key(|(_, stats)| std::cmp::Reverse(stats.mistakes));
+        broken
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn noun(word: &str) -> Noun {
+        Noun::from_card(&VocabularyCard {
+            id: 1,
+            word: word.to_string(),
+            example: String::new(),
+            alternatives: Vec::new(),
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn rules_follow_the_ending() {
+        assert_eq!(noun("la canción").rule(), GenderRule::Ion);
+        assert_eq!(noun("la ciudad").rule(), GenderRule::Dad);
+        assert_eq!(noun("el mensaje").rule(), GenderRule::Aje);
+        assert_eq!(noun("el problema").rule(), GenderRule::GreekMa);
+        assert_eq!(noun("la noche").rule(), GenderRule::NoRule);
+        assert!(!noun("la casa").is_exception());
+    }
+
+    #[test]
+    fn exceptions_contradict_their_rule() {
+        assert!(noun("el día").is_exception());
+        assert!(noun("la mano").is_exception());
+        assert!(noun("el avión").is_exception());
+        assert!(noun("la flor").is_exception());
+        assert!(noun("el sofá").is_exception());
+    }
+
+    #[test]
+    fn stressed_a_takes_el_in_the_singular() {
+        let agua = noun("el agua");
+        assert_eq!(agua.gender, Gender::Feminine);
+        assert_eq!(agua.article(ArticleForm::Definite), "el");
+        assert_eq!(agua.article(ArticleForm::Indefinite), "un");
+        assert_eq!(agua.article(ArticleForm::Plural), "las");
+        assert_eq!(agua.form(ArticleForm::Plural), "aguas");
+    }
+
+    #[test]
+    fn nouns_of_both_genders_are_skipped() {
+        let card = VocabularyCard {
+            id: 1,
+            word: "el/la amigo/a".to_string(),
+            example: String::new(),
+            alternatives: Vec::new(),
+        };
+        assert_eq!(Noun::from_card(&card), None);
+    }
+}
@@ -0,0 +1,67 @@
+/// Direction of a completed swipe
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Swipe {
+    Left,
+    Right,
+    Up,
+    Down,
+}
+
+/// Distance in pixels that always counts as a swipe
+const SWIPE_DISTANCE: f64 = 80.0;
+
+/// Shorter drags still count as a swipe when flicked quickly
+const MIN_FLICK_DISTANCE: f64 = 30.0;
+
+/// Flick velocity threshold in pixels per millisecond
+const FLICK_VELOCITY: f64 = 0.5;
+
+/// Classify a drag of (dx, dy) pixels over `elapsed_ms` as a swipe, if it was one
+pub fn classify_swipe(dx: f64, dy: f64, elapsed_ms: f64) -> Option<Swipe> {
+    let horizontal = dx.abs() >= dy.abs();
+    let distance = if horizontal { dx.abs() } else { dy.abs() };
+    let velocity = distance / elapsed_ms.max(1.0);
+
+    if distance < SWIPE_DISTANCE && (distance < MIN_FLICK_DISTANCE || velocity < FLICK_VELOCITY) {
+        return None;
+    }
+
+    Some(match (horizontal, dx < 0.0, dy < 0.0) {
+        (true, true, _) => Swipe::Left,
+        (true, false, _) => Swipe::Right,
+        (false, _, true) => Swipe::Up,
+        (false, _, false) => Swipe::Down,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn long_drags_are_swipes_at_any_speed() {
+        assert_eq!(
+            classify_swipe(-SWIPE_DISTANCE, 0.0, 2000.0),
+            Some(Swipe::Left)
+        );
+        assert_eq!(classify_swipe(SWIPE_DISTANCE - 1.0, 0.0, 2000.0), None);
+    }
+
+    #[test]
+    fn short_drags_must_be_flicked() {
+        // 40px in 50ms is 0.8px/ms, in 200ms only 0.2px/ms
+        assert_eq!(classify_swipe(40.0, 0.0, 50.0), Some(Swipe::Right));
+        assert_eq!(classify_swipe(40.0, 0.0, 200.0), None);
+        // Too short even when fast
+        assert_eq!(classify_swipe(MIN_FLICK_DISTANCE - 1.0, 0.0, 1.0), None);
+    }
+
+    #[test]
+    fn the_longer_axis_decides_the_direction() {
+        assert_eq!(classify_swipe(-100.0, -60.0, 300.0), Some(Swipe::Left));
+        assert_eq!(classify_swipe(60.0, -100.0, 300.0), Some(Swipe::Up));
+        assert_eq!(classify_swipe(-30.0, 90.0, 300.0), Some(Swipe::Down));
+        // Ties go to the horizontal axis
+        assert_eq!(classify_swipe(90.0, 90.0, 300.0), Some(Swipe::Right));
+    }
+}
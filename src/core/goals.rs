@@ -0,0 +1,134 @@
+//! The daily study goal, and progress towards it from the event log.
+
+use crate::core::clock;
+use crate::core::events::StudyEvent;
+use crate::core::stats::cards_per_day;
+use serde::{Deserialize, Serialize};
+
+/// Longest pause between two events still counted as study time
+const IDLE_GAP_MS: f64 = 2.0 * 60_000.0;
+
+/// What the daily goal is measured in
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum GoalUnit {
+    #[default]
+    Cards,
+    Minutes,
+}
+
+impl GoalUnit {
+    pub const ALL: [GoalUnit; 2] = [GoalUnit::Cards, GoalUnit::Minutes];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            GoalUnit::Cards => "cards",
+            GoalUnit::Minutes => "minutes",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DailyGoal {
+    pub unit: GoalUnit,
+    pub target: u32,
+}
+
+impl Default for DailyGoal {
+    fn default() -> Self {
+        Self {
+            unit: GoalUnit::Cards,
+            target: 20,
+        }
+    }
+}
+
+impl DailyGoal {
+    /// Cards studied or minutes spent on `day`, in the goal's unit
+    pub fn progress(&self, events: &[StudyEvent], day: i64) -> u32 {
+        match self.unit {
+            GoalUnit::Cards => cards_per_day(events, day, day)[0] as u32,
+            GoalUnit::Minutes => minutes_studied(events, day),
+        }
+    }
+
+    pub fn is_met(&self, events: &[StudyEvent], day: i64) -> bool {
+        self.progress(events, day) >= self.target
+    }
+}
+
+/// Minutes spent studying on `day`, from the time between its events. Pauses
+/// longer than a couple of minutes are counted as breaks.
+pub fn minutes_studied(events: &[StudyEvent], day: i64) -> u32 {
+    let mut times: Vec<f64> = events
+        .iter()
+        .filter(|event| event.day == day)
+        .map(|event| event.at)
+        .collect();
+    times.sort_by(f64::total_cmp);
+    let studied_ms: f64 = times
+        .windows(2)
+        .map(|pair| (pair[1] - pair[0]).min(IDLE_GAP_MS))
+        .sum();
+    (studied_ms / 60_000.0) as u32
+}
+
+/// Day of the next goal reminder at `hour`: today if that's still ahead and
+/// the goal isn't met yet, otherwise tomorrow
+pub fn next_reminder_day(now_ms: f64, today: i64, hour: u32, goal_met: bool) -> i64 {
+    if !goal_met && clock::local_time_ms(today, hour) > now_ms {
+        today
+    } else {
+        today + 1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::events::EventKind;
+
+    fn event(at_minutes: f64, card_id: u32) -> StudyEvent {
+        StudyEvent {
+            at: at_minutes * 60_000.0,
+            day: 0,
+            card_id,
+            kind: EventKind::View,
+        }
+    }
+
+    #[test]
+    fn long_pauses_are_breaks() {
+        // Two bursts of three minutes, an hour apart
+        let events = [
+            event(0.0, 1),
+            event(1.5, 2),
+            event(3.0, 3),
+            event(63.0, 4),
+            event(64.0, 5),
+            event(66.0, 6),
+        ];
+        // 3 minutes, a break counted as 2, then 3 more
+        assert_eq!(minutes_studied(&events, 0), 8);
+        assert_eq!(minutes_studied(&events, 1), 0);
+    }
+
+    #[test]
+    fn goals_count_distinct_cards() {
+        let goal = DailyGoal {
+            unit: GoalUnit::Cards,
+            target: 2,
+        };
+        let events = [event(0.0, 1), event(0.5, 1)];
+        assert_eq!(goal.progress(&events, 0), 1);
+        assert!(!goal.is_met(&events, 0));
+        assert!(goal.is_met(&[event(0.0, 1), event(0.5, 2)], 0));
+    }
+
+    #[test]
+    fn reminders_skip_to_tomorrow_once_met_or_missed() {
+        let nine_am = clock::local_time_ms(10, 9);
+        assert_eq!(next_reminder_day(nine_am, 10, 19, false), 10);
+        assert_eq!(next_reminder_day(nine_am, 10, 19, true), 11);
+        assert_eq!(next_reminder_day(nine_am, 10, 8, false), 11);
+    }
+}
@@ -0,0 +1,686 @@
+//! Locating a card's headword inside its example sentence.
+//!
+//! Headwords are dictionary forms (`la casa`, `tener`, `to buy`) while
+//! examples use inflected ones (`casas`, `tengo`, `bought`), so matching
+//! generates the plural, gender, verb and comparative forms of each headword
+//! and compares them against the accent-folded words of the sentence.
+
+use crate::core::language::Language;
+use crate::core::text::{fold_accent, normalize};
+use std::ops::Range;
+
+/// Words ignored when reading headwords and search queries
+const ARTICLES: [&str; 12] = [
+    "el", "la", "los", "las", "un", "una", "unos", "unas", "the", "a", "an", "to",
+];
+
+pub fn is_article(word: &str) -> bool {
+    ARTICLES.contains(&word)
+}
+
+/// Normalized alternatives of a headword, each as a list of words
+///
+/// Slash notation is expanded (`el/la amigo/a` gives `amigo` and `amiga`,
+/// `to get on/go up` gives `get on` and `go up`), leading articles and
+/// parenthesised notes such as `(essence)` are dropped.
+fn lemmas(headword: &str) -> Vec<Vec<String>> {
+    let mut alternatives: Vec<Vec<String>> = Vec::new();
+    for segment in strip_notes(headword).split('/') {
+        let words: Vec<String> = normalize(segment)
+            .split_whitespace()
+            .map(str::to_string)
+            .collect();
+        let content: Vec<String> = words
+            .iter()
+            .skip_while(|w| is_article(w))
+            .cloned()
+            .collect();
+        if content.is_empty() && words.len() != 1 {
+            continue;
+        }
+
+        // A one or two letter segment right after a word is an ending (`amigo/a`)
+        let is_suffix = words.len() == 1
+            && words[0].chars().count() <= 2
+            && segment.starts_with(|c: char| !c.is_whitespace());
+        match alternatives.last() {
+            Some(previous) if is_suffix => {
+                let mut variant = previous.clone();
+                if let Some(last) = variant.last_mut() {
+                    *last = match last.strip_suffix('o') {
+                        Some(stem) => format!("{}{}", stem, words[0]),
+                        None => format!("{}{}", last, words[0]),
+                    };
+                }
+                alternatives.push(variant);
+            }
+            _ if content.is_empty() => {}
+            _ => alternatives.push(content),
+        }
+    }
+    alternatives
+}
+
+/// Byte range of the headword (or one of its forms) in the example sentence
+///
+/// Exact forms are preferred over inflected ones, and whole phrases over the
+/// first word of a phrase (`to sit down` in `Sit here`).
+pub fn locate_headword(headword: &str, example: &str, language: Language) -> Option<Range<usize>> {
+    let alternatives = lemmas(headword);
+    let tokens = tokenize(example);
+
+    // English verbs are written `to ...` and have no comparative forms
+    let is_verb = normalize(headword).starts_with("to ");
+    let exact = |token: &str, lemma: &str| token == lemma;
+    let inflected = |token: &str, lemma: &str| is_form_of(token, lemma, language, is_verb);
+
+    find_phrase(&alternatives, &tokens, exact)
+        .or_else(|| find_phrase(&alternatives, &tokens, inflected))
+        .or_else(|| {
+            let heads: Vec<Vec<String>> = alternatives
+                .iter()
+                .filter(|words| words.len() > 1)
+                .map(|words| words[..1].to_vec())
+                .collect();
+            find_phrase(&heads, &tokens, inflected)
+        })
+}
+
+/// Remove parenthesised notes, e.g. `to be (location)`
+fn strip_notes(headword: &str) -> String {
+    let mut depth = 0;
+    headword
+        .chars()
+        .filter(|c| match c {
+            '(' => {
+                depth += 1;
+                false
+            }
+            ')' => {
+                depth -= 1;
+                false
+            }
+            _ => depth == 0,
+        })
+        .collect()
+}
+
+struct Token {
+    range: Range<usize>,
+    folded: String,
+}
+
+/// Words of a sentence with their byte ranges; apostrophes stay inside words
+fn tokenize(text: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut start = None;
+    let is_word_char = |c: char| c.is_alphanumeric() || c == '\'' || c == '’';
+    for (i, c) in text
+        .char_indices()
+        .chain(std::iter::once((text.len(), ' ')))
+    {
+        match (start, is_word_char(c)) {
+            (None, true) => start = Some(i),
+            (Some(s), false) => {
+                let word = text[s..i].trim_end_matches(['\'', '’']);
+                tokens.push(Token {
+                    range: s..s + word.len(),
+                    folded: word
+                        .chars()
+                        .flat_map(char::to_lowercase)
+                        .map(|c| if c == '’' { '\'' } else { fold_accent(c) })
+                        .collect(),
+                });
+                start = None;
+            }
+            _ => {}
+        }
+    }
+    tokens
+}
+
+/// First occurrence of any alternative, matching words pairwise
+fn find_phrase(
+    alternatives: &[Vec<String>],
+    tokens: &[Token],
+    matches: impl Fn(&str, &str) -> bool,
+) -> Option<Range<usize>> {
+    alternatives.iter().find_map(|words| {
+        tokens.windows(words.len()).find_map(|window| {
+            window
+                .iter()
+                .zip(words)
+                .all(|(token, word)| matches(&token.folded, word))
+                .then(|| window[0].range.start..window[window.len() - 1].range.end)
+        })
+    })
+}
+
+/// Whether a normalized sentence word is an inflected form of a lemma word
+fn is_form_of(token: &str, lemma: &str, language: Language, is_verb: bool) -> bool {
+    token == lemma
+        || match language {
+            Language::Spanish => is_spanish_form(token, lemma),
+            Language::English => is_english_form(token, lemma, is_verb),
+        }
+}
+
+// === Spanish ===
+
+/// Short forms used before masculine singular nouns (`buen día`)
+const SPANISH_APOCOPE: [(&str, &str); 8] = [
+    ("bueno", "buen"),
+    ("malo", "mal"),
+    ("uno", "un"),
+    ("alguno", "algun"),
+    ("ninguno", "ningun"),
+    ("primero", "primer"),
+    ("tercero", "tercer"),
+    ("grande", "gran"),
+];
+
+/// Unstressed pronouns attached to infinitives, gerunds and commands
+const SPANISH_CLITICS: [&str; 11] = [
+    "me", "te", "se", "nos", "os", "lo", "la", "los", "las", "le", "les",
+];
+
+const AR_ENDINGS: [&str; 28] = [
+    "o", "as", "a", "amos", "ais", "an", "e", "aste", "asteis", "aron", "aba", "abas", "abamos",
+    "abais", "aban", "es", "emos", "eis", "en", "ando", "ado", "ada", "ados", "adas", "ar", "ad",
+    "ara", "aran",
+];
+
+const ER_IR_ENDINGS: [&str; 40] = [
+    "o", "es", "e", "emos", "eis", "en", "imos", "is", "i", "iste", "io", "isteis", "ieron", "ia",
+    "ias", "iamos", "iais", "ian", "a", "as", "amos", "ais", "an", "iendo", "yendo", "yo", "yeron",
+    "ido", "ida", "idos", "idas", "er", "ir", "ed", "id", "iera", "ieran", "eron", "ieramos", "ye",
+];
+
+/// Future and conditional endings, added to the whole infinitive
+const FUTURE_ENDINGS: [&str; 11] = [
+    "e", "as", "a", "emos", "eis", "an", "ia", "ias", "iamos", "iais", "ian",
+];
+
+/// Forms of common verbs that no stem rule produces
+const SPANISH_IRREGULAR: [(&str, &[&str]); 15] = [
+    (
+        "ser",
+        &[
+            "soy", "eres", "es", "somos", "sois", "son", "era", "eras", "eramos", "eran", "fui",
+            "fuiste", "fue", "fuimos", "fueron", "sea", "seas", "sean", "sido", "siendo", "se",
+        ],
+    ),
+    (
+        "ir",
+        &[
+            "voy", "vas", "va", "vamos", "vais", "van", "iba", "ibas", "ibamos", "iban", "fui",
+            "fuiste", "fue", "fuimos", "fueron", "vaya", "vayas", "vayamos", "vayan", "yendo",
+            "ido", "ve", "id",
+        ],
+    ),
+    (
+        "estar",
+        &[
+            "estoy",
+            "estuve",
+            "estuviste",
+            "estuvo",
+            "estuvimos",
+            "estuvieron",
+            "este",
+            "estes",
+            "esten",
+        ],
+    ),
+    (
+        "tener",
+        &[
+            "tengo",
+            "tuve",
+            "tuviste",
+            "tuvo",
+            "tuvimos",
+            "tuvieron",
+            "tenga",
+            "tengas",
+            "tengan",
+            "ten",
+            "tendre",
+            "tendra",
+            "tendremos",
+            "tendran",
+            "tendria",
+        ],
+    ),
+    (
+        "venir",
+        &[
+            "vengo", "vine", "viniste", "vino", "vinimos", "vinieron", "venga", "vengan", "ven",
+            "vendre", "vendra", "vendria", "viniendo",
+        ],
+    ),
+    (
+        "hacer",
+        &[
+            "hago", "hice", "hiciste", "hizo", "hicimos", "hicieron", "haga", "hagas", "hagamos",
+            "hagan", "haz", "hare", "hara", "haremos", "haran", "haria", "hecho",
+        ],
+    ),
+    (
+        "poder",
+        &[
+            "pude", "pudiste", "pudo", "pudimos", "pudieron", "podre", "podra", "podria",
+            "pudiendo",
+        ],
+    ),
+    (
+        "querer",
+        &[
+            "quise",
+            "quisiste",
+            "quiso",
+            "quisimos",
+            "quisieron",
+            "querre",
+            "querra",
+            "querria",
+        ],
+    ),
+    (
+        "poner",
+        &[
+            "pongo", "puse", "pusiste", "puso", "pusimos", "pusieron", "ponga", "pongan", "pon",
+            "pondre", "pondra", "puesto",
+        ],
+    ),
+    (
+        "salir",
+        &[
+            "salgo", "salga", "salgan", "sal", "saldre", "saldra", "saldria",
+        ],
+    ),
+    (
+        "decir",
+        &[
+            "digo", "dices", "dice", "dicen", "dije", "dijiste", "dijo", "dijimos", "dijeron",
+            "diga", "digan", "di", "dire", "dira", "dicho", "diciendo",
+        ],
+    ),
+    (
+        "saber",
+        &[
+            "se", "supe", "supiste", "supo", "supimos", "supieron", "sepa", "sepan", "sabre",
+            "sabra",
+        ],
+    ),
+    (
+        "ver",
+        &[
+            "veo", "vi", "viste", "vio", "vimos", "vieron", "vea", "veas", "veamos", "vean",
+            "visto", "viendo", "veia", "veias", "veiamos", "veian",
+        ],
+    ),
+    (
+        "dar",
+        &[
+            "doy", "di", "diste", "dio", "dimos", "dieron", "de", "des", "demos", "den",
+        ],
+    ),
+    (
+        "haber",
+        &[
+            "he", "has", "ha", "hemos", "han", "hay", "habia", "hubo", "haya", "habido",
+        ],
+    ),
+];
+
+fn is_spanish_form(token: &str, lemma: &str) -> bool {
+    if spanish_nominal_forms(lemma)
+        .iter()
+        .any(|form| form == token)
+    {
+        return true;
+    }
+    let Some(infinitive) = spanish_infinitive(lemma) else {
+        return false;
+    };
+    without_clitics(token)
+        .iter()
+        .any(|verb| is_spanish_verb_form(verb, infinitive))
+}
+
+/// Plural, gender and short forms of a noun or adjective
+fn spanish_nominal_forms(word: &str) -> Vec<String> {
+    let mut forms = Vec::new();
+    let mut add_plural = |singular: &str| {
+        match singular.strip_suffix('z') {
+            Some(stem) => forms.push(format!("{}ces", stem)),
+            None => forms.push(format!("{}s", singular)),
+        }
+        forms.push(format!("{}es", singular));
+    };
+    add_plural(word);
+    if let Some(stem) = word.strip_suffix('o') {
+        let feminine = format!("{}a", stem);
+        add_plural(&feminine);
+        forms.push(feminine);
+    }
+    forms.extend(
+        SPANISH_APOCOPE
+            .iter()
+            .filter(|(full, _)| *full == word)
+            .map(|(_, short)| short.to_string()),
+    );
+    forms
+}
+
+/// The infinitive of a verb lemma, without a reflexive `se`
+fn spanish_infinitive(lemma: &str) -> Option<&str> {
+    let verb = lemma.strip_suffix("se").unwrap_or(lemma);
+    (verb.len() >= 2 && ["ar", "er", "ir"].iter().any(|e| verb.ends_with(e))).then_some(verb)
+}
+
+/// The token itself and the token with up to two trailing pronouns removed
+fn without_clitics(token: &str) -> Vec<&str> {
+    let mut candidates = vec![token];
+    for clitic in SPANISH_CLITICS {
+        if let Some(rest) = token.strip_suffix(clitic).filter(|r| r.len() > 2) {
+            candidates.push(rest);
+            for second in SPANISH_CLITICS {
+                if let Some(inner) = rest.strip_suffix(second).filter(|r| r.len() > 2) {
+                    candidates.push(inner);
+                }
+            }
+        }
+    }
+    candidates
+}
+
+fn is_spanish_verb_form(token: &str, infinitive: &str) -> bool {
+    if SPANISH_IRREGULAR
+        .iter()
+        .any(|(verb, forms)| *verb == infinitive && forms.contains(&token))
+    {
+        return true;
+    }
+    if token
+        .strip_prefix(infinitive)
+        .is_some_and(|ending| FUTURE_ENDINGS.contains(&ending))
+    {
+        return true;
+    }
+
+    let (stem, theme) = infinitive.split_at(infinitive.len() - 2);
+    if stem.is_empty() {
+        return false;
+    }
+    let endings: &[&str] = if theme == "ar" {
+        &AR_ENDINGS
+    } else {
+        &ER_IR_ENDINGS
+    };
+    spanish_stems(stem, theme).iter().any(|stem| {
+        token
+            .strip_prefix(stem.as_str())
+            .is_some_and(|ending| endings.contains(&ending))
+    })
+}
+
+/// Regular stem plus spelling changes (`pagué`, `conozco`) and vowel changes
+/// in the last syllable (`quiero`, `puedo`, `pido`, `juego`)
+fn spanish_stems(stem: &str, theme: &str) -> Vec<String> {
+    let mut spellings = vec![stem.to_string()];
+    let replace_end = |from: &str, to: &str| {
+        stem.strip_suffix(from)
+            .map(|start| format!("{}{}", start, to))
+    };
+    let changes: &[(&str, &str)] = if theme == "ar" {
+        &[("c", "qu"), ("g", "gu"), ("z", "c")]
+    } else {
+        &[("g", "j"), ("gu", "g"), ("c", "z"), ("c", "zc")]
+    };
+    spellings.extend(
+        changes
+            .iter()
+            .filter_map(|(from, to)| replace_end(from, to)),
+    );
+
+    let mut stems = spellings.clone();
+    for spelling in &spellings {
+        // The u in gu/qu only marks a hard consonant (`seguir`)
+        let Some(position) = spelling
+            .char_indices()
+            .rev()
+            .find(|(i, c)| {
+                "aeio".contains(*c) || (*c == 'u' && !spelling[..*i].ends_with(['g', 'q']))
+            })
+            .map(|(i, _)| i)
+        else {
+            continue;
+        };
+        let (start, end) = (&spelling[..position], &spelling[position + 1..]);
+        let replacements: &[&str] = match &spelling[position..position + 1] {
+            "e" => &["ie", "i"],
+            "o" => &["ue", "u"],
+            "u" => &["ue"],
+            _ => &[],
+        };
+        stems.extend(
+            replacements
+                .iter()
+                .map(|vowel| format!("{}{}{}", start, vowel, end)),
+        );
+    }
+    stems
+}
+
+// === English ===
+
+/// Irregular plurals, verb forms and comparatives
+const ENGLISH_IRREGULAR: [(&str, &[&str]); 56] = [
+    ("be", &["am", "is", "are", "was", "were", "been", "being"]),
+    ("have", &["has", "had", "having"]),
+    ("do", &["does", "did", "done", "doing"]),
+    ("make", &["made"]),
+    ("go", &["goes", "went", "gone"]),
+    ("get", &["got", "gotten"]),
+    ("eat", &["ate", "eaten"]),
+    ("drink", &["drank", "drunk"]),
+    ("come", &["came"]),
+    ("leave", &["left"]),
+    ("give", &["gave", "given"]),
+    ("think", &["thought"]),
+    ("buy", &["bought"]),
+    ("sell", &["sold"]),
+    ("pay", &["paid"]),
+    ("feel", &["felt"]),
+    ("sleep", &["slept"]),
+    ("run", &["ran"]),
+    ("fly", &["flew", "flown"]),
+    ("find", &["found"]),
+    ("lose", &["lost"]),
+    ("know", &["knew", "known"]),
+    ("meet", &["met"]),
+    ("teach", &["taught"]),
+    ("write", &["wrote", "written"]),
+    ("see", &["saw", "seen"]),
+    ("speak", &["spoke", "spoken"]),
+    ("swim", &["swam", "swum"]),
+    ("sing", &["sang", "sung"]),
+    ("spend", &["spent"]),
+    ("send", &["sent"]),
+    ("understand", &["understood"]),
+    ("forget", &["forgot", "forgotten"]),
+    ("take", &["took", "taken"]),
+    ("wear", &["wore", "worn"]),
+    ("sit", &["sat"]),
+    ("win", &["won"]),
+    ("begin", &["began", "begun"]),
+    ("say", &["said"]),
+    ("shine", &["shone"]),
+    ("drive", &["drove", "driven"]),
+    ("bring", &["brought"]),
+    ("keep", &["kept"]),
+    ("can", &["could"]),
+    ("good", &["better", "best"]),
+    ("bad", &["worse", "worst"]),
+    ("much", &["more", "most"]),
+    ("many", &["more", "most"]),
+    ("little", &["less", "least"]),
+    ("far", &["farther", "further"]),
+    ("person", &["people"]),
+    ("child", &["children"]),
+    ("man", &["men"]),
+    ("woman", &["women"]),
+    ("foot", &["feet"]),
+    ("tooth", &["teeth"]),
+];
+
+fn is_english_form(token: &str, lemma: &str, is_verb: bool) -> bool {
+    ENGLISH_IRREGULAR
+        .iter()
+        .any(|(word, forms)| *word == lemma && forms.contains(&token))
+        || english_regular_forms(lemma, is_verb)
+            .iter()
+            .any(|form| form == token)
+}
+
+/// Regular plural/third person, past, -ing and (for non-verbs) comparative forms
+fn english_regular_forms(word: &str, is_verb: bool) -> Vec<String> {
+    let chars: Vec<char> = word.chars().collect();
+    let is_vowel = |c: char| "aeiou".contains(c);
+    let endings: &[&str] = if is_verb {
+        &["ing", "ed"]
+    } else {
+        &["ing", "ed", "er", "est"]
+    };
+    let mut forms = Vec::new();
+
+    forms.push(format!("{}s", word));
+    if ["s", "x", "z", "ch", "sh", "o"]
+        .iter()
+        .any(|e| word.ends_with(e))
+    {
+        forms.push(format!("{}es", word));
+    }
+
+    let consonant_y = word
+        .strip_suffix('y')
+        .filter(|stem| stem.chars().last().is_some_and(|c| !is_vowel(c)));
+    if let Some(stem) = consonant_y {
+        forms.push(format!("{}ies", stem));
+        forms.extend(endings[1..].iter().map(|e| format!("{}i{}", stem, e)));
+    }
+    if let Some(stem) = word.strip_suffix("fe").or(word.strip_suffix('f')) {
+        forms.push(format!("{}ves", stem));
+    }
+    if let Some(stem) = word.strip_suffix("ie") {
+        forms.push(format!("{}ying", stem));
+    }
+
+    // Silent e is dropped before vowel endings (live, living, lived)
+    let base = match word.strip_suffix('e') {
+        Some(stem) if !word.ends_with("ee") => stem.to_string(),
+        _ => word.to_string(),
+    };
+    // A single final consonant after a single vowel doubles (stop, stopped)
+    let doubled = match chars.as_slice() {
+        [.., a, b, c] if !is_vowel(*a) && is_vowel(*b) && !is_vowel(*c) && !"wxy".contains(*c) => {
+            Some(format!("{}{}", word, c))
+        }
+        [b, c] if is_vowel(*b) && !is_vowel(*c) && !"wxy".contains(*c) => {
+            Some(format!("{}{}", word, c))
+        }
+        _ => None,
+    };
+    for stem in std::iter::once(base).chain(doubled) {
+        forms.extend(endings.iter().map(|e| format!("{}{}", stem, e)));
+    }
+    forms
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(alternatives: &[&[&str]]) -> Vec<Vec<String>> {
+        alternatives
+            .iter()
+            .map(|words| words.iter().map(|w| w.to_string()).collect())
+            .collect()
+    }
+
+    /// The part of the example the headword was located at
+    fn located<'a>(headword: &str, example: &'a str, language: Language) -> Option<&'a str> {
+        locate_headword(headword, example, language).map(|range| &example[range])
+    }
+
+    #[test]
+    fn leading_articles_are_stripped() {
+        assert_eq!(lemmas("el agua"), words(&[&["agua"]]));
+        assert_eq!(lemmas("the water"), words(&[&["water"]]));
+        assert_eq!(lemmas("to buy"), words(&[&["buy"]]));
+        // Articles only mean something before a word
+        assert_eq!(
+            lemmas("el ratón de la casa"),
+            words(&[&["raton", "de", "la", "casa"]])
+        );
+        assert!(lemmas("la").is_empty());
+    }
+
+    #[test]
+    fn slash_variants_are_expanded() {
+        assert_eq!(lemmas("el/la amigo/a"), words(&[&["amigo"], &["amiga"]]));
+        assert_eq!(
+            lemmas("el/la profesor/a"),
+            words(&[&["profesor"], &["profesora"]])
+        );
+        assert_eq!(lemmas("much/many"), words(&[&["much"], &["many"]]));
+        assert_eq!(
+            lemmas("to get on/go up"),
+            words(&[&["get", "on"], &["go", "up"]])
+        );
+        assert_eq!(lemmas("to be (location)"), words(&[&["be"]]));
+    }
+
+    #[test]
+    fn exact_forms_win_over_inflected_ones() {
+        let example = "Los libros y el libro";
+        let range = locate_headword("el libro", example, Language::Spanish).unwrap();
+        assert_eq!(range, example.rfind("libro").unwrap()..example.len());
+        assert_eq!(
+            located("la amiga", "Mis amigas llegan", Language::Spanish),
+            Some("amigas")
+        );
+        assert_eq!(
+            located("el/la amigo/a", "Mi amiga llega", Language::Spanish),
+            Some("amiga")
+        );
+    }
+
+    #[test]
+    fn phrases_fall_back_to_their_first_word() {
+        assert_eq!(
+            located("to sit down", "Sit here, please", Language::English),
+            Some("Sit")
+        );
+        assert_eq!(
+            located("to get on/go up", "We go up the stairs", Language::English),
+            Some("go up")
+        );
+    }
+
+    #[test]
+    fn contractions_and_synonyms_are_not_located() {
+        // The English gaps of the bundled stages: a contraction hides the
+        // headword, or the example uses another word for it
+        let en = Language::English;
+        assert_eq!(located("to be (location)", "I'm in the park now", en), None);
+        assert_eq!(located("to be able/can", "I can't go today", en), None);
+        assert_eq!(located("the hour", "What time is it?", en), None);
+        assert_eq!(
+            located("the airplane", "The plane leaves at three", en),
+            None
+        );
+    }
+}
@@ -0,0 +1,226 @@
+//! Spanish plurals and adjective agreement.
+
+use crate::core::accent::{AccentRule, accentuation, strip_tildes};
+use crate::core::gender::{ArticleForm, Gender, Noun};
+use crate::core::syllables::{Stress, syllabify};
+
+/// Common adjectives with one form for both genders
+pub const GENDERLESS_ADJECTIVES: [&str; 23] = [
+    "grande",
+    "dulce",
+    "caliente",
+    "fuerte",
+    "azul",
+    "verde",
+    "gris",
+    "feliz",
+    "triste",
+    "interesante",
+    "difícil",
+    "fácil",
+    "importante",
+    "joven",
+    "útil",
+    "amable",
+    "alegre",
+    "pobre",
+    "libre",
+    "inteligente",
+    "suficiente",
+    "marrón",
+    "cortés",
+];
+
+/// Adjectives in -or that don't add -a for the feminine
+const COMPARATIVES: [&str; 6] = ["mejor", "peor", "mayor", "menor", "superior", "inferior"];
+
+/// Plural of a noun or noun phrase; only the first word of a compound
+/// changes (`fin de semana` → `fines de semana`)
+pub fn plural(phrase: &str) -> String {
+    match phrase.split_once(' ') {
+        Some((head, rest)) => format!("{} {}", plural_word(head), rest),
+        None => plural_word(phrase),
+    }
+}
+
+fn plural_word(word: &str) -> String {
+    let lower = word.to_lowercase();
+    let Some(last) = lower.chars().last() else {
+        return String::new();
+    };
+    let stress = syllabify(word).map(|s| (s.syllables.len(), s.stress()));
+
+    match last {
+        // Unstressed or stressed vowels just add -s (casas, cafés, sofás)
+        'a' | 'e' | 'i' | 'o' | 'u' | 'á' | 'é' | 'ó' => format!("{}s", word),
+        'í' | 'ú' => format!("{}es", word),
+        'z' => format!("{}ces", &word[..word.len() - 1]),
+        // Words ending in -s or -x only change when the last syllable is
+        // stressed (mes → meses, but el lunes → los lunes)
+        's' | 'x' if !matches!(stress, Some((1, _)) | Some((_, Stress::Aguda))) => word.to_string(),
+        _ => with_es(word, stress.map(|(_, stress)| stress)),
+    }
+}
+
+/// Form of an adjective, given in the masculine singular, agreeing with a
+/// noun (`blanco` → `blancas`, `trabajador` → `trabajadora`, `feliz` → `felices`)
+pub fn adjective_form(masculine: &str, gender: Gender, is_plural: bool) -> String {
+    let singular = match gender {
+        Gender::Masculine => masculine.to_string(),
+        Gender::Feminine => feminine(masculine),
+    };
+    if is_plural {
+        plural(&singular)
+    } else {
+        singular
+    }
+}
+
+fn feminine(masculine: &str) -> String {
+    if GENDERLESS_ADJECTIVES.contains(&masculine) {
+        masculine.to_string()
+    } else if let Some(stem) = masculine.strip_suffix('o') {
+        format!("{}a", stem)
+    } else if masculine.ends_with("or") && !COMPARATIVES.contains(&masculine) {
+        format!("{}a", masculine)
+    } else if ["án", "ín", "ón", "és"]
+        .iter()
+        .any(|end| masculine.ends_with(end))
+    {
+        // alemán → alemana, inglés → inglesa
+        format!("{}a", strip_tildes(masculine))
+    } else {
+        masculine.to_string()
+    }
+}
+
+/// A noun with its definite article and an adjective, all agreeing
+/// (`la casa` + `blanco` → `las casas blancas`)
+pub fn noun_phrase(noun: &Noun, adjective: &str, is_plural: bool) -> String {
+    let form = if is_plural {
+        ArticleForm::Plural
+    } else {
+        ArticleForm::Definite
+    };
+    format!(
+        "{} {} {}",
+        noun.article(form),
+        noun.form(form),
+        adjective_form(adjective, noun.gender, is_plural)
+    )
+}
+
+/// Add -es, moving the tilde as the stress now falls one syllable earlier
+/// from the end (canción → canciones, examen → exámenes)
+fn with_es(word: &str, stress: Option<Stress>) -> String {
+    let Some(accented) = accentuation(word) else {
+        return format!("{}es", word);
+    };
+    match (stress, accented.tilde, accented.rule) {
+        // A hiatus tilde stays (país → países)
+        (_, Some(_), AccentRule::Hiatus) => format!("{}es", word),
+        (Some(Stress::Aguda), Some(_), _) if accented.syllables.syllables.len() > 1 => {
+            format!("{}es", strip_tildes(word))
+        }
+        (Some(Stress::Llana), None, _) if word.ends_with('n') => {
+            let mut syllables = accented.syllables.syllables.clone();
+            let stressed = accented.syllables.stressed;
+            syllables[stressed] = add_tilde(&syllables[stressed]);
+            format!("{}es", syllables.concat())
+        }
+        _ => format!("{}es", word),
+    }
+}
+
+/// Put a tilde on the vowel carrying the stress of a syllable: the strong
+/// vowel of a diphthong, otherwise the last vowel
+fn add_tilde(syllable: &str) -> String {
+    let chars: Vec<char> = syllable.chars().collect();
+    let position = chars
+        .iter()
+        .position(|c| "aeo".contains(*c))
+        .or_else(|| chars.iter().rposition(|c| "iu".contains(*c)));
+    chars
+        .iter()
+        .enumerate()
+        .map(|(i, &c)| match (Some(i) == position, c) {
+            (true, 'a') => 'á',
+            (true, 'e') => 'é',
+            (true, 'i') => 'í',
+            (true, 'o') => 'ó',
+            (true, 'u') => 'ú',
+            _ => c,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn regular_plurals() {
+        assert_eq!(plural("casa"), "casas");
+        assert_eq!(plural("café"), "cafés");
+        assert_eq!(plural("hotel"), "hoteles");
+        assert_eq!(plural("reloj"), "relojes");
+        assert_eq!(plural("luz"), "luces");
+        assert_eq!(plural("fin de semana"), "fines de semana");
+    }
+
+    #[test]
+    fn tildes_move_with_the_stress() {
+        assert_eq!(plural("canción"), "canciones");
+        assert_eq!(plural("autobús"), "autobuses");
+        assert_eq!(plural("examen"), "exámenes");
+        assert_eq!(plural("joven"), "jóvenes");
+        assert_eq!(plural("país"), "países");
+        assert_eq!(plural("árbol"), "árboles");
+    }
+
+    #[test]
+    fn adjectives_agree_in_gender_and_number() {
+        assert_eq!(adjective_form("blanco", Gender::Feminine, true), "blancas");
+        assert_eq!(
+            adjective_form("trabajador", Gender::Feminine, false),
+            "trabajadora"
+        );
+        assert_eq!(adjective_form("mejor", Gender::Feminine, false), "mejor");
+        assert_eq!(adjective_form("alemán", Gender::Feminine, true), "alemanas");
+        assert_eq!(
+            adjective_form("alemán", Gender::Masculine, true),
+            "alemanes"
+        );
+        assert_eq!(adjective_form("feliz", Gender::Feminine, true), "felices");
+        assert_eq!(adjective_form("azul", Gender::Masculine, true), "azules");
+        assert_eq!(adjective_form("marrón", Gender::Feminine, true), "marrones");
+    }
+
+    #[test]
+    fn noun_phrases_agree() {
+        let noun = |word: &str, gender| Noun {
+            card_id: 1,
+            word: word.to_string(),
+            gender,
+        };
+        assert_eq!(
+            noun_phrase(&noun("casa", Gender::Feminine), "blanco", true),
+            "las casas blancas"
+        );
+        assert_eq!(
+            noun_phrase(&noun("agua", Gender::Feminine), "frío", false),
+            "el agua fría"
+        );
+        assert_eq!(
+            noun_phrase(&noun("lápiz", Gender::Masculine), "verde", true),
+            "los lápices verdes"
+        );
+    }
+
+    #[test]
+    fn words_in_s_after_an_unstressed_syllable_do_not_change() {
+        assert_eq!(plural("mes"), "meses");
+        assert_eq!(plural("lunes"), "lunes");
+        assert_eq!(plural("crisis"), "crisis");
+    }
+}
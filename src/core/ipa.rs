@@ -0,0 +1,306 @@
+//! Broad IPA transcription of written Spanish.
+//!
+//! Spanish spelling is close to phonemic, so each letter (or digraph) maps
+//! to a phone depending on its neighbours and the chosen dialect. The stress
+//! mark comes from the syllabifier.
+
+use crate::core::syllables::syllabify;
+use serde::{Deserialize, Serialize};
+
+/// Regional pronunciation used for the transcription
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Dialect {
+    /// Northern and central Spain: `z`/`ce` as [θ]
+    #[default]
+    Castilian,
+    /// Mexico and most of Central America
+    Mexican,
+    /// Buenos Aires and Montevideo: `ll`/`y` as [ʃ]
+    Rioplatense,
+    /// Cuba, Puerto Rico and the Dominican Republic: `j` as [h]
+    Caribbean,
+    /// Andes: `ll` kept apart from `y` as [ʎ]
+    Andean,
+}
+
+impl Dialect {
+    pub const ALL: [Dialect; 5] = [
+        Dialect::Castilian,
+        Dialect::Mexican,
+        Dialect::Rioplatense,
+        Dialect::Caribbean,
+        Dialect::Andean,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Dialect::Castilian => "Castilian (Spain)",
+            Dialect::Mexican => "Mexican",
+            Dialect::Rioplatense => "Rioplatense (Argentina, Uruguay)",
+            Dialect::Caribbean => "Caribbean",
+            Dialect::Andean => "Andean",
+        }
+    }
+
+    /// Distinción: `z` and soft `c` differ from `s`
+    fn has_distincion(self) -> bool {
+        self == Dialect::Castilian
+    }
+
+    fn jota(self) -> &'static str {
+        match self {
+            Dialect::Caribbean => "h",
+            _ => "x",
+        }
+    }
+
+    fn ll(self) -> &'static str {
+        match self {
+            Dialect::Rioplatense => "ʃ",
+            Dialect::Andean => "ʎ",
+            _ => "ʝ",
+        }
+    }
+
+    fn consonant_y(self) -> &'static str {
+        match self {
+            Dialect::Rioplatense => "ʃ",
+            _ => "ʝ",
+        }
+    }
+}
+
+/// Words where `x` keeps its old [x] sound, mostly Mexican place names
+const X_AS_JOTA: [&str; 9] = [
+    "mexico",
+    "mexicano",
+    "mexicana",
+    "mexicanos",
+    "mexicanas",
+    "oaxaca",
+    "texas",
+    "xavier",
+    "ximena",
+];
+
+/// Transcribe every word of a text, ignoring punctuation
+pub fn transcribe_text(text: &str, dialect: Dialect) -> String {
+    text.split(|c: char| !c.is_alphabetic())
+        .filter(|word| !word.is_empty())
+        .map(|word| transcribe_word(word, dialect))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Transcribe a single word, with `ˈ` before the stressed syllable
+pub fn transcribe_word(word: &str, dialect: Dialect) -> String {
+    let chars: Vec<char> = word
+        .chars()
+        .map(|c| c.to_lowercase().next().unwrap_or(c))
+        .collect();
+    let folded: String = chars.iter().map(|c| unaccented(*c)).collect();
+    let at = |i: usize| chars.get(i).copied();
+    let is_front = |c: Option<char>| matches!(c, Some('e' | 'i' | 'é' | 'í'));
+
+    // Each phone remembers the letter it starts at, to place the stress mark
+    let mut phones: Vec<(usize, &str)> = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let prev = i.checked_sub(1).and_then(at);
+        let next = at(i + 1);
+        let (phone, len): (&str, usize) = match chars[i] {
+            'a' | 'á' => ("a", 1),
+            'e' | 'é' => ("e", 1),
+            'o' | 'ó' => ("o", 1),
+            'í' => ("i", 1),
+            'ú' => ("u", 1),
+            'i' if is_glide(&chars, i) => ("j", 1),
+            'i' => ("i", 1),
+            'u' | 'ü' if is_glide(&chars, i) => ("w", 1),
+            'u' | 'ü' => ("u", 1),
+            'y' if chars.len() == 1 => ("i", 1),
+            'y' if next.is_none() || !is_vowel(next) => ("j", 1),
+            'y' => (dialect.consonant_y(), 1),
+            'h' => ("", 1),
+            'b' | 'v' => ("b", 1),
+            'c' if next == Some('h') => ("tʃ", 2),
+            'c' if is_front(next) && dialect.has_distincion() => ("θ", 1),
+            'c' if is_front(next) => ("s", 1),
+            'c' | 'k' => ("k", 1),
+            'q' if next == Some('u') => ("k", 2),
+            'q' => ("k", 1),
+            'g' if next == Some('u') && is_front(at(i + 2)) => ("g", 2),
+            'g' if is_front(next) => (dialect.jota(), 1),
+            'g' => ("g", 1),
+            'j' => (dialect.jota(), 1),
+            'l' if next == Some('l') => (dialect.ll(), 2),
+            'ñ' => ("ɲ", 1),
+            'r' if next == Some('r') => ("r", 2),
+            'r' if prev.is_none() || matches!(prev, Some('n' | 'l' | 's')) => ("r", 1),
+            'r' => ("ɾ", 1),
+            'z' if dialect.has_distincion() => ("θ", 1),
+            'z' => ("s", 1),
+            'x' if X_AS_JOTA.contains(&folded.as_str()) => (dialect.jota(), 1),
+            'x' if i == 0 => ("s", 1),
+            'x' => {
+                // The k closes the previous syllable (e·xa·men, [ekˈsamen])
+                phones.push((i.saturating_sub(1), "k"));
+                ("s", 1)
+            }
+            'd' if is_vowel(prev) && (is_vowel(next) || next.is_none()) => ("ð", 1),
+            'd' if next.is_none() => ("ð", 1),
+            // With seseo the s of `sc` merges into the c (fascinante)
+            's' if next == Some('c') && is_front(at(i + 2)) && !dialect.has_distincion() => ("", 1),
+            'w' => ("w", 1),
+            c => (letter_phone(c), 1),
+        };
+        phones.push((i, phone));
+        i += len;
+    }
+
+    let stress_at = syllabify(word).filter(|s| s.syllables.len() > 1).map(|s| {
+        s.syllables[..s.stressed]
+            .iter()
+            .map(|syllable| syllable.chars().count())
+            .sum::<usize>()
+    });
+
+    let mut out = String::new();
+    let mut marked = false;
+    for (position, phone) in phones {
+        if !marked && stress_at.is_some_and(|at| position >= at) && !phone.is_empty() {
+            out.push('ˈ');
+            marked = true;
+        }
+        out.push_str(phone);
+    }
+    out
+}
+
+/// Letters that are pronounced as written
+fn letter_phone(c: char) -> &'static str {
+    match c {
+        'f' => "f",
+        'l' => "l",
+        'm' => "m",
+        'n' => "n",
+        'p' => "p",
+        's' => "s",
+        't' => "t",
+        'd' => "d",
+        _ => "",
+    }
+}
+
+fn unaccented(c: char) -> char {
+    match c {
+        'á' => 'a',
+        'é' => 'e',
+        'í' => 'i',
+        'ó' => 'o',
+        'ú' | 'ü' => 'u',
+        _ => c,
+    }
+}
+
+fn is_vowel(c: Option<char>) -> bool {
+    c.is_some_and(|c| "aeiouáéíóúü".contains(c))
+}
+
+/// An unaccented i or u next to another vowel is a glide, unless it is the
+/// second of two weak vowels (`ciudad`). A word-final y is not a vowel here:
+/// it is the glide itself, and the i or u before it the nucleus (`muy`).
+fn is_glide(chars: &[char], i: usize) -> bool {
+    let prev = i.checked_sub(1).map(|p| chars[p]);
+    let next = chars.get(i + 1).copied();
+    // The u of que/qui/gue/gui is silent and handled with its consonant
+    let after_silent_u = prev == Some('u')
+        && i >= 2
+        && matches!(chars[i - 2], 'q' | 'g')
+        && matches!(chars[i], 'e' | 'i');
+    if after_silent_u {
+        return false;
+    }
+    if is_vowel(next) {
+        return true;
+    }
+    is_vowel(prev) && !(matches!(prev, Some('i' | 'u')) && is_glide(chars, i - 1))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ipa(word: &str) -> String {
+        transcribe_word(word, Dialect::Castilian)
+    }
+
+    #[test]
+    fn regular_words() {
+        assert_eq!(ipa("casa"), "ˈkasa");
+        assert_eq!(ipa("perro"), "ˈpero");
+        assert_eq!(ipa("pero"), "ˈpeɾo");
+        assert_eq!(ipa("noche"), "ˈnotʃe");
+        assert_eq!(ipa("mañana"), "maˈɲana");
+        assert_eq!(ipa("hola"), "ˈola");
+    }
+
+    #[test]
+    fn c_and_z_follow_the_dialect() {
+        assert_eq!(ipa("cerveza"), "θeɾˈbeθa");
+        assert_eq!(transcribe_word("cerveza", Dialect::Mexican), "seɾˈbesa");
+        assert_eq!(ipa("queso"), "ˈkeso");
+        assert_eq!(ipa("piscina"), "pisˈθina");
+        assert_eq!(transcribe_word("piscina", Dialect::Mexican), "piˈsina");
+    }
+
+    #[test]
+    fn g_and_j() {
+        assert_eq!(ipa("gente"), "ˈxente");
+        assert_eq!(ipa("guerra"), "ˈgera");
+        assert_eq!(ipa("jefe"), "ˈxefe");
+        assert_eq!(transcribe_word("jefe", Dialect::Caribbean), "ˈhefe");
+    }
+
+    #[test]
+    fn ll_and_y_follow_the_dialect() {
+        assert_eq!(ipa("calle"), "ˈkaʝe");
+        assert_eq!(transcribe_word("calle", Dialect::Rioplatense), "ˈkaʃe");
+        assert_eq!(transcribe_word("calle", Dialect::Andean), "ˈkaʎe");
+        assert_eq!(ipa("mayo"), "ˈmaʝo");
+        assert_eq!(ipa("hoy"), "oj");
+        assert_eq!(ipa("y"), "i");
+    }
+
+    #[test]
+    fn intervocalic_and_final_d() {
+        assert_eq!(ipa("cansado"), "kanˈsaðo");
+        assert_eq!(ipa("ciudad"), "θjuˈðað");
+        assert_eq!(ipa("donde"), "ˈdonde");
+    }
+
+    #[test]
+    fn x_in_names_and_words() {
+        assert_eq!(ipa("examen"), "ekˈsamen");
+        assert_eq!(transcribe_word("México", Dialect::Mexican), "ˈmexiko");
+        assert_eq!(ipa("xilófono"), "siˈlofono");
+    }
+
+    #[test]
+    fn diphthongs_and_hiatus() {
+        assert_eq!(ipa("tiempo"), "ˈtjempo");
+        assert_eq!(ipa("aire"), "ˈajɾe");
+        assert_eq!(ipa("día"), "ˈdia");
+        assert_eq!(ipa("muy"), "muj");
+        assert_eq!(ipa("buey"), "bwej");
+        assert_eq!(ipa("hoy"), "oj");
+    }
+
+    #[test]
+    fn texts_drop_punctuation() {
+        assert_eq!(
+            transcribe_text("¿Qué hora es?", Dialect::Mexican),
+            "ke ˈoɾa es"
+        );
+    }
+}
@@ -0,0 +1,138 @@
+use leptos::prelude::*;
+use std::rc::Rc;
+
+/// A keyboard shortcut and the action it triggers
+#[derive(Clone)]
+pub struct KeyBinding {
+    /// `KeyboardEvent.key` values that trigger the action
+    pub keys: Vec<&'static str>,
+    /// How the keys are shown in the shortcut overlay
+    pub label: &'static str,
+    pub description: &'static str,
+    action: Rc<dyn Fn()>,
+}
+
+impl KeyBinding {
+    pub fn new(
+        keys: &[&'static str],
+        label: &'static str,
+        description: &'static str,
+        action: impl Fn() + 'static,
+    ) -> Self {
+        Self {
+            keys: keys.to_vec(),
+            label,
+            description,
+            action: Rc::new(action),
+        }
+    }
+}
+
+/// Global context holding the key bindings of every mounted component
+#[derive(Clone, Copy)]
+pub struct KeymapContext {
+    bindings: RwSignal<Vec<(usize, KeyBinding)>, LocalStorage>,
+    next_id: StoredValue<usize>,
+}
+
+impl KeymapContext {
+    pub fn new() -> Self {
+        Self {
+            bindings: RwSignal::new_local(Vec::new()),
+            next_id: StoredValue::new(0),
+        }
+    }
+
+    fn register(&self, bindings: Vec<KeyBinding>) -> usize {
+        let id = self.next_id.get_value();
+        self.next_id.set_value(id + 1);
+        self.bindings
+            .update(|all| all.extend(bindings.into_iter().map(|binding| (id, binding))));
+        id
+    }
+
+    fn unregister(&self, id: usize) {
+        self.bindings
+            .update(|all| all.retain(|(owner, _)| *owner != id));
+    }
+
+    /// Run the most recently registered action bound to `key`
+    pub fn dispatch(&self, key: &str) -> bool {
+        let action = self.bindings.with_untracked(|all| {
+            all.iter()
+                .rev()
+                .find(|(_, binding)| binding.keys.contains(&key))
+                .map(|(_, binding)| binding.action.clone())
+        });
+        match action {
+            Some(action) => {
+                action();
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// All registered bindings, in registration order
+    pub fn get_all(&self) -> Vec<KeyBinding> {
+        self.bindings
+            .read()
+            .iter()
+            .map(|(_, binding)| binding.clone())
+            .collect()
+    }
+}
+
+/// Register key bindings for as long as the calling component is mounted
+pub fn use_key_bindings(bindings: Vec<KeyBinding>) {
+    let keymap = expect_context::<KeymapContext>();
+    let id = keymap.register(bindings);
+    on_cleanup(move || keymap.unregister(id));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::cell::Cell;
+
+    /// A binding for `keys` that counts its calls in `count`
+    fn counting(keys: &[&'static str], count: &Rc<Cell<u32>>) -> KeyBinding {
+        let count = count.clone();
+        KeyBinding::new(keys, keys[0], "Count", move || count.set(count.get() + 1))
+    }
+
+    #[test]
+    fn any_of_the_keys_triggers_the_action() {
+        let keymap = KeymapContext::new();
+        let count = Rc::new(Cell::new(0));
+        keymap.register(vec![counting(&["y", "Y", "2"], &count)]);
+
+        assert!(keymap.dispatch("y"));
+        assert!(keymap.dispatch("2"));
+        assert!(!keymap.dispatch("n"));
+        // Keys match whole, not by prefix
+        assert!(!keymap.dispatch("yes"));
+        assert_eq!(count.get(), 2);
+    }
+
+    #[test]
+    fn the_latest_registration_wins_until_it_is_removed() {
+        let keymap = KeymapContext::new();
+        let (page, dialog) = (Rc::new(Cell::new(0)), Rc::new(Cell::new(0)));
+        keymap.register(vec![counting(&["Enter"], &page)]);
+        let id = keymap.register(vec![
+            counting(&["Enter"], &dialog),
+            counting(&["Escape"], &dialog),
+        ]);
+        assert_eq!(keymap.get_all().len(), 3);
+
+        keymap.dispatch("Enter");
+        assert_eq!((page.get(), dialog.get()), (0, 1));
+
+        keymap.unregister(id);
+        keymap.dispatch("Enter");
+        assert!(!keymap.dispatch("Escape"));
+        assert_eq!((page.get(), dialog.get()), (1, 1));
+        assert_eq!(keymap.get_all().len(), 1);
+    }
+}
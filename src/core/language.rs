@@ -25,6 +25,14 @@ impl Language {
         }
     }
 
+    /// BCP 47 tag used for speech synthesis and recognition
+    pub fn speech_code(&self) -> &'static str {
+        match self {
+            Language::Spanish => "es-ES",
+            Language::English => "en-US",
+        }
+    }
+
     /// Flag emoji representation
     pub fn flag_emoji(&self) -> &'static str {
         match self {
@@ -0,0 +1,337 @@
+//! How well each card is known, measured separately in each direction:
+//! recognition (Spanish → English) and production (English → Spanish).
+
+use crate::core::random::random_below;
+use crate::core::storage;
+use crate::data::{DirectionMode, LearningDirection};
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// `localStorage` key holding the mastery as JSON
+const STORAGE_KEY: &str = "vamos.mastery";
+
+/// Answers known in a row before a card counts as mastered
+pub const MASTERED_STREAK: u32 = 3;
+
+/// Self-graded answers for one card in one direction
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CardMastery {
+    pub known: u32,
+    pub unknown: u32,
+    /// Answers known since the last miss
+    pub streak: u32,
+}
+
+impl CardMastery {
+    pub fn is_mastered(&self) -> bool {
+        self.streak >= MASTERED_STREAK
+    }
+
+    fn reviews(&self) -> u32 {
+        self.known + self.unknown
+    }
+}
+
+/// Mastery of every graded card, by global card ID
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Mastery {
+    /// Spanish → English
+    pub recognition: BTreeMap<u32, CardMastery>,
+    /// English → Spanish
+    pub production: BTreeMap<u32, CardMastery>,
+}
+
+impl Mastery {
+    fn cards(&self, direction: LearningDirection) -> &BTreeMap<u32, CardMastery> {
+        match direction {
+            LearningDirection::SpanishToEnglish => &self.recognition,
+            LearningDirection::EnglishToSpanish => &self.production,
+        }
+    }
+
+    pub fn get(&self, card_id: u32, direction: LearningDirection) -> CardMastery {
+        self.cards(direction)
+            .get(&card_id)
+            .copied()
+            .unwrap_or_default()
+    }
+
+    pub fn record(&mut self, card_id: u32, direction: LearningDirection, known: bool) {
+        let cards = match direction {
+            LearningDirection::SpanishToEnglish => &mut self.recognition,
+            LearningDirection::EnglishToSpanish => &mut self.production,
+        };
+        let mastery = cards.entry(card_id).or_default();
+        if known {
+            mastery.known += 1;
+            mastery.streak += 1;
+        } else {
+            mastery.unknown += 1;
+            mastery.streak = 0;
+        }
+    }
+
+    /// Count the card as mastered in both directions, as for cards of the
+    /// stages a placement test skips
+    pub fn mark_known(&mut self, card_id: u32) {
+        for cards in [&mut self.recognition, &mut self.production] {
+            let mastery = cards.entry(card_id).or_default();
+            if !mastery.is_mastered() {
+                mastery.known += MASTERED_STREAK - mastery.streak;
+                mastery.streak = MASTERED_STREAK;
+            }
+        }
+    }
+
+    /// How many of the cards are mastered in the direction
+    pub fn mastered_count(
+        &self,
+        card_ids: impl IntoIterator<Item = u32>,
+        direction: LearningDirection,
+    ) -> usize {
+        card_ids
+            .into_iter()
+            .filter(|id| self.get(*id, direction).is_mastered())
+            .count()
+    }
+}
+
+/// Global context for card mastery, saved on every change
+#[derive(Clone, Copy)]
+pub struct MasteryContext {
+    pub mastery: RwSignal<Mastery>,
+}
+
+impl MasteryContext {
+    pub fn new() -> Self {
+        let saved = storage::load(STORAGE_KEY)
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default();
+        Self {
+            mastery: RwSignal::new(saved),
+        }
+    }
+
+    fn save(&self) {
+        if let Ok(json) = serde_json::to_string(&*self.mastery.read_untracked()) {
+            storage::save(STORAGE_KEY, &json);
+        }
+    }
+
+    pub fn record(&self, card_id: u32, direction: LearningDirection, known: bool) {
+        self.mastery.update(|m| m.record(card_id, direction, known));
+        self.save();
+    }
+
+    pub fn mark_known(&self, card_ids: impl IntoIterator<Item = u32>) {
+        self.mastery
+            .update(|m| card_ids.into_iter().for_each(|id| m.mark_known(id)));
+        self.save();
+    }
+}
+
+/// How a mixed-direction session picks the direction of each card
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum MixedOrder {
+    /// Spanish first on even cards, English first on odd ones
+    #[default]
+    Alternate,
+    Random,
+    /// The direction the card is known least well in
+    Weakest,
+}
+
+impl MixedOrder {
+    pub const ALL: [MixedOrder; 3] = [
+        MixedOrder::Alternate,
+        MixedOrder::Random,
+        MixedOrder::Weakest,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            MixedOrder::Alternate => "Alternate",
+            MixedOrder::Random => "Random",
+            MixedOrder::Weakest => "Weakest direction first",
+        }
+    }
+}
+
+/// Direction to show the card at `index` in, in a mixed-direction session
+pub fn pick_direction(
+    order: MixedOrder,
+    index: usize,
+    recognition: CardMastery,
+    production: CardMastery,
+) -> LearningDirection {
+    let alternate = if index.is_multiple_of(2) {
+        LearningDirection::SpanishToEnglish
+    } else {
+        LearningDirection::EnglishToSpanish
+    };
+    match order {
+        MixedOrder::Alternate => alternate,
+        MixedOrder::Random if random_below(2) == 0 => LearningDirection::SpanishToEnglish,
+        MixedOrder::Random => LearningDirection::EnglishToSpanish,
+        MixedOrder::Weakest => {
+            let weakness = |m: CardMastery| (m.streak, m.reviews());
+            match weakness(recognition).cmp(&weakness(production)) {
+                std::cmp::Ordering::Less => LearningDirection::SpanishToEnglish,
+                std::cmp::Ordering::Greater => LearningDirection::EnglishToSpanish,
+                std::cmp::Ordering::Equal => alternate,
+            }
+        }
+    }
+}
+
+/// Direction to show the card at `index` of a session in. Mixed sessions
+/// pick one per card, falling back to Spanish first while there is no card
+/// to look at (e.g. a stage that doesn't exist).
+pub fn card_direction(
+    mode: DirectionMode,
+    order: MixedOrder,
+    index: usize,
+    card_id: Option<u32>,
+    mastery: &Mastery,
+) -> LearningDirection {
+    match (mode, card_id) {
+        (DirectionMode::Single(direction), _) => direction,
+        (DirectionMode::Mixed, None) => LearningDirection::SpanishToEnglish,
+        (DirectionMode::Mixed, Some(card_id)) => pick_direction(
+            order,
+            index,
+            mastery.get(card_id, LearningDirection::SpanishToEnglish),
+            mastery.get(card_id, LearningDirection::EnglishToSpanish),
+        ),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::{STAGE_COUNT, load_vocabulary_stage};
+
+    #[test]
+    fn directions_are_tracked_separately() {
+        let mut mastery = Mastery::default();
+        for _ in 0..MASTERED_STREAK {
+            mastery.record(7, LearningDirection::SpanishToEnglish, true);
+        }
+        mastery.record(7, LearningDirection::EnglishToSpanish, false);
+
+        assert!(
+            mastery
+                .get(7, LearningDirection::SpanishToEnglish)
+                .is_mastered()
+        );
+        assert!(
+            !mastery
+                .get(7, LearningDirection::EnglishToSpanish)
+                .is_mastered()
+        );
+        assert_eq!(
+            mastery.mastered_count([7, 8], LearningDirection::SpanishToEnglish),
+            1
+        );
+    }
+
+    #[test]
+    fn a_miss_resets_the_streak() {
+        let mut mastery = Mastery::default();
+        mastery.record(1, LearningDirection::EnglishToSpanish, true);
+        mastery.record(1, LearningDirection::EnglishToSpanish, true);
+        mastery.record(1, LearningDirection::EnglishToSpanish, false);
+        assert_eq!(
+            mastery.get(1, LearningDirection::EnglishToSpanish),
+            CardMastery {
+                known: 2,
+                unknown: 1,
+                streak: 0
+            }
+        );
+    }
+
+    #[test]
+    fn marking_known_masters_both_directions() {
+        let mut mastery = Mastery::default();
+        mastery.record(4, LearningDirection::SpanishToEnglish, true);
+        mastery.mark_known(4);
+        assert_eq!(
+            mastery.get(4, LearningDirection::SpanishToEnglish),
+            CardMastery {
+                known: MASTERED_STREAK,
+                unknown: 0,
+                streak: MASTERED_STREAK
+            }
+        );
+        assert!(
+            mastery
+                .get(4, LearningDirection::EnglishToSpanish)
+                .is_mastered()
+        );
+    }
+
+    #[test]
+    fn alternate_switches_every_card() {
+        let new = CardMastery::default();
+        assert_eq!(
+            pick_direction(MixedOrder::Alternate, 0, new, new),
+            LearningDirection::SpanishToEnglish
+        );
+        assert_eq!(
+            pick_direction(MixedOrder::Alternate, 1, new, new),
+            LearningDirection::EnglishToSpanish
+        );
+    }
+
+    #[test]
+    fn weakest_picks_the_less_known_direction() {
+        let strong = CardMastery {
+            known: 4,
+            unknown: 0,
+            streak: 4,
+        };
+        let weak = CardMastery {
+            known: 1,
+            unknown: 2,
+            streak: 0,
+        };
+        assert_eq!(
+            pick_direction(MixedOrder::Weakest, 0, strong, weak),
+            LearningDirection::EnglishToSpanish
+        );
+        assert_eq!(
+            pick_direction(MixedOrder::Weakest, 1, weak, strong),
+            LearningDirection::SpanishToEnglish
+        );
+        // Unseen in both directions: fall back to alternating
+        let new = CardMastery::default();
+        assert_eq!(
+            pick_direction(MixedOrder::Weakest, 1, new, new),
+            LearningDirection::EnglishToSpanish
+        );
+    }
+
+    #[test]
+    fn mixed_sessions_over_missing_stages_start_from_spanish() {
+        let mode = DirectionMode::from_query(Some("both"));
+        let mastery = Mastery::default();
+        for stage in [0, STAGE_COUNT + 1] {
+            let first_id = load_vocabulary_stage(stage, "es")
+                .ok()
+                .and_then(|cards| cards.first().map(|c| c.id));
+            assert_eq!(first_id, None);
+            assert_eq!(
+                card_direction(mode, MixedOrder::Alternate, 1, first_id, &mastery),
+                LearningDirection::SpanishToEnglish
+            );
+        }
+        let first_id = load_vocabulary_stage(1, "es").unwrap()[0].id;
+        assert_eq!(
+            card_direction(mode, MixedOrder::Alternate, 1, Some(first_id), &mastery),
+            LearningDirection::EnglishToSpanish
+        );
+    }
+}
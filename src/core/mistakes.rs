@@ -0,0 +1,121 @@
+//! The Mistakes deck: cards missed in a quiz, kept until they are answered
+//! right a few times in a row.
+
+use crate::core::storage;
+use crate::data::stage_for_card_id;
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// `localStorage` key holding the deck as JSON
+const STORAGE_KEY: &str = "vamos.mistakes";
+
+/// Right answers in a row that take a card out of the deck
+pub const CORRECT_TO_CLEAR: u32 = 3;
+
+/// Missed cards, by global card ID, with their right answers in a row since
+/// the last miss
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MistakesDeck {
+    cards: BTreeMap<u32, u32>,
+}
+
+impl MistakesDeck {
+    /// A miss puts the card in the deck, or back at the start of its streak;
+    /// right answers count towards taking it out
+    pub fn record(&mut self, card_id: u32, correct: bool) {
+        if !correct {
+            self.cards.insert(card_id, 0);
+        } else if let Some(streak) = self.cards.get_mut(&card_id) {
+            *streak += 1;
+            if *streak >= CORRECT_TO_CLEAR {
+                self.cards.remove(&card_id);
+            }
+        }
+    }
+
+    /// Right answers in a row of a card in the deck
+    pub fn streak(&self, card_id: u32) -> Option<u32> {
+        self.cards.get(&card_id).copied()
+    }
+
+    pub fn len(&self) -> usize {
+        self.cards.len()
+    }
+
+    /// (stage, card_id) pairs of the deck, in card order
+    pub fn cards(&self) -> Vec<(u32, u32)> {
+        self.cards
+            .keys()
+            .filter_map(|id| stage_for_card_id(*id).map(|stage| (stage, *id)))
+            .collect()
+    }
+}
+
+/// Global context for the Mistakes deck, saved on every change
+#[derive(Clone, Copy)]
+pub struct MistakesContext {
+    pub deck: RwSignal<MistakesDeck>,
+}
+
+impl MistakesContext {
+    pub fn new() -> Self {
+        let saved = storage::load(STORAGE_KEY)
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default();
+        Self {
+            deck: RwSignal::new(saved),
+        }
+    }
+
+    pub fn record(&self, card_id: u32, correct: bool) {
+        // Right answers about cards outside the deck change nothing
+        if correct && self.deck.read_untracked().streak(card_id).is_none() {
+            return;
+        }
+        self.deck.update(|d| d.record(card_id, correct));
+        if let Ok(json) = serde_json::to_string(&*self.deck.read_untracked()) {
+            storage::save(STORAGE_KEY, &json);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn misses_join_the_deck() {
+        let mut deck = MistakesDeck::default();
+        deck.record(5, true);
+        assert_eq!(deck.len(), 0);
+        deck.record(25, false);
+        deck.record(5, false);
+        assert_eq!(deck.cards(), [(1, 5), (2, 25)]);
+    }
+
+    #[test]
+    fn cards_leave_after_enough_right_answers_in_a_row() {
+        let mut deck = MistakesDeck::default();
+        deck.record(7, false);
+        for _ in 1..CORRECT_TO_CLEAR {
+            deck.record(7, true);
+        }
+        deck.record(7, false);
+        assert_eq!(deck.streak(7), Some(0));
+        for _ in 0..CORRECT_TO_CLEAR {
+            deck.record(7, true);
+        }
+        assert_eq!(deck.streak(7), None);
+    }
+
+    #[test]
+    fn the_deck_survives_a_round_trip() {
+        let mut deck = MistakesDeck::default();
+        deck.record(3, false);
+        deck.record(3, true);
+        let json = serde_json::to_string(&deck).unwrap();
+        assert_eq!(serde_json::from_str::<MistakesDeck>(&json).unwrap(), deck);
+    }
+}
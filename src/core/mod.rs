@@ -1,4 +1,40 @@
+pub mod accent;
+pub mod clock;
+pub mod cloze;
+pub mod dictation;
+pub mod events;
 pub mod favorites;
+pub mod gender;
+pub mod gesture;
+pub mod goals;
+pub mod headword;
+pub mod inflection;
+pub mod ipa;
+pub mod keymap;
 pub mod language;
+pub mod mastery;
+pub mod mistakes;
+pub mod numbers;
+pub mod placement;
+pub mod progress;
+pub mod qr;
+pub mod random;
+pub mod reminders;
+pub mod scramble;
+pub mod search;
+pub mod session;
+pub mod settings;
+pub mod share;
+pub mod speech;
+pub mod stats;
+pub mod storage;
+pub mod syllables;
+pub mod text;
 
+pub use events::EventLogContext;
 pub use favorites::FavoritesContext;
+pub use keymap::KeymapContext;
+pub use mastery::MasteryContext;
+pub use mistakes::MistakesContext;
+pub use progress::ProgressContext;
+pub use settings::SettingsContext;
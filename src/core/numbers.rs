@@ -0,0 +1,484 @@
+//! Spanish numbers, dates and clock times in words.
+
+use crate::core::gender::Gender;
+use crate::core::text::normalize;
+
+const UNITS: [&str; 30] = [
+    "cero",
+    "uno",
+    "dos",
+    "tres",
+    "cuatro",
+    "cinco",
+    "seis",
+    "siete",
+    "ocho",
+    "nueve",
+    "diez",
+    "once",
+    "doce",
+    "trece",
+    "catorce",
+    "quince",
+    "dieciséis",
+    "diecisiete",
+    "dieciocho",
+    "diecinueve",
+    "veinte",
+    "veintiuno",
+    "veintidós",
+    "veintitrés",
+    "veinticuatro",
+    "veinticinco",
+    "veintiséis",
+    "veintisiete",
+    "veintiocho",
+    "veintinueve",
+];
+
+const TENS: [&str; 10] = [
+    "",
+    "diez",
+    "veinte",
+    "treinta",
+    "cuarenta",
+    "cincuenta",
+    "sesenta",
+    "setenta",
+    "ochenta",
+    "noventa",
+];
+
+/// Hundreds in the masculine; the feminine swaps the final -os for -as
+const HUNDREDS: [&str; 10] = [
+    "",
+    "ciento",
+    "doscientos",
+    "trescientos",
+    "cuatrocientos",
+    "quinientos",
+    "seiscientos",
+    "setecientos",
+    "ochocientos",
+    "novecientos",
+];
+
+const MONTHS: [&str; 12] = [
+    "enero",
+    "febrero",
+    "marzo",
+    "abril",
+    "mayo",
+    "junio",
+    "julio",
+    "agosto",
+    "septiembre",
+    "octubre",
+    "noviembre",
+    "diciembre",
+];
+
+/// A number in words. `None` gives the counting form (`veintiuno`); with a
+/// gender it agrees with a following noun (`veintiún libros`,
+/// `doscientas una casas`).
+pub fn number_to_words(n: u64, gender: Option<Gender>) -> String {
+    if n == 0 {
+        return UNITS[0].to_string();
+    }
+    const MILLION: u64 = 1_000_000;
+    const BILLION: u64 = 1_000_000_000_000;
+    const TRILLION: u64 = 1_000_000_000_000_000_000;
+
+    let mut parts = Vec::new();
+    // Millions and up are masculine nouns: `un millón`, `veintiún millones`.
+    // Trillions cover the rest of `u64`, up to `dieciocho trillones …`.
+    let scales = [
+        (n / TRILLION, "trillón", "trillones"),
+        (n / BILLION % MILLION, "billón", "billones"),
+        (n / MILLION % MILLION, "millón", "millones"),
+    ];
+    for (count, singular, plural) in scales {
+        match count {
+            0 => {}
+            1 => parts.push(format!("un {}", singular)),
+            _ => parts.push(format!(
+                "{} {}",
+                below_million(count, Some(Gender::Masculine)),
+                plural
+            )),
+        }
+    }
+    let rest = n % MILLION;
+    if rest > 0 {
+        parts.push(below_million(rest, gender));
+    }
+    parts.join(" ")
+}
+
+fn below_million(n: u64, gender: Option<Gender>) -> String {
+    let thousands = n / 1000;
+    let rest = n % 1000;
+    let mut parts = Vec::new();
+    match thousands {
+        0 => {}
+        // mil, never un mil
+        1 => parts.push("mil".to_string()),
+        _ => {
+            // Before `mil` uno shortens to un (`veintiún mil`), or una for a feminine noun
+            let gender = Some(gender.unwrap_or(Gender::Masculine));
+            parts.push(format!("{} mil", below_thousand(thousands, gender)));
+        }
+    }
+    if rest > 0 {
+        parts.push(below_thousand(rest, gender));
+    }
+    parts.join(" ")
+}
+
+fn below_thousand(n: u64, gender: Option<Gender>) -> String {
+    let hundreds = (n / 100) as usize;
+    let rest = n % 100;
+    let mut parts = Vec::new();
+    if n == 100 {
+        return "cien".to_string();
+    }
+    if hundreds > 0 {
+        let word = HUNDREDS[hundreds];
+        parts.push(match gender {
+            Some(Gender::Feminine) if hundreds > 1 => format!("{}as", &word[..word.len() - 2]),
+            _ => word.to_string(),
+        });
+    }
+    if rest > 0 {
+        parts.push(below_hundred(rest, gender));
+    }
+    parts.join(" ")
+}
+
+fn below_hundred(n: u64, gender: Option<Gender>) -> String {
+    let words = if n < 30 {
+        UNITS[n as usize].to_string()
+    } else if n.is_multiple_of(10) {
+        TENS[(n / 10) as usize].to_string()
+    } else {
+        format!("{} y {}", TENS[(n / 10) as usize], UNITS[(n % 10) as usize])
+    };
+    if n % 10 != 1 || n == 11 {
+        return words;
+    }
+    // uno agrees with a following noun: un libro, una casa, veintiún años
+    let stem = &words[..words.len() - "uno".len()];
+    match gender {
+        None => words,
+        Some(Gender::Feminine) => format!("{}una", stem),
+        Some(Gender::Masculine) if n == 21 => "veintiún".to_string(),
+        Some(Gender::Masculine) => format!("{}un", stem),
+    }
+}
+
+/// A date as said in Spanish (`el quince de marzo de dos mil veinticuatro`)
+pub fn date_to_words(day: u32, month: u32, year: u32) -> String {
+    format!(
+        "el {} de {} de {}",
+        number_to_words(day as u64, None),
+        MONTHS[(month as usize).clamp(1, 12) - 1],
+        number_to_words(year as u64, None)
+    )
+}
+
+/// Ways of saying a clock time, the usual one first (`son las tres y
+/// cuarto`, `son las tres y quince`). Hours may be given on a 24 hour clock.
+pub fn time_phrases(hour: u32, minute: u32) -> Vec<String> {
+    // Past the half hour, count down to the next hour
+    let (shown_hour, joiner, minutes) = if minute > 30 {
+        (hour + 1, "menos", 60 - minute)
+    } else {
+        (hour, "y", minute)
+    };
+    let hour_phrase = |h: u32| match h % 12 {
+        1 => "es la una".to_string(),
+        0 => "son las doce".to_string(),
+        h => format!(
+            "son las {}",
+            number_to_words(h as u64, Some(Gender::Feminine))
+        ),
+    };
+
+    let mut phrases = Vec::new();
+    if minute == 0 {
+        phrases.push(hour_phrase(hour));
+        phrases.push(format!("{} en punto", hour_phrase(hour)));
+        return phrases;
+    }
+    let minute_words = |m: u32| number_to_words(m as u64, None);
+    let named = match minutes {
+        15 => Some("cuarto"),
+        30 => Some("media"),
+        _ => None,
+    };
+    if let Some(named) = named {
+        phrases.push(format!("{} {} {}", hour_phrase(shown_hour), joiner, named));
+    }
+    phrases.push(format!(
+        "{} {} {}",
+        hour_phrase(shown_hour),
+        joiner,
+        minute_words(minutes)
+    ));
+    if minute > 30 {
+        phrases.push(format!("{} y {}", hour_phrase(hour), minute_words(minute)));
+    }
+    phrases
+}
+
+/// Something to say in Spanish: a number (possibly counting a noun), a date
+/// or a clock time
+#[derive(Debug, Clone, PartialEq)]
+pub enum DrillItem {
+    Number {
+        value: u64,
+        /// Noun counted, in the singular and plural, and its gender
+        noun: Option<(&'static str, &'static str, Gender)>,
+    },
+    Date {
+        day: u32,
+        month: u32,
+        year: u32,
+    },
+    Time {
+        hour: u32,
+        minute: u32,
+    },
+}
+
+impl DrillItem {
+    /// The item written with digits (`231 personas`, `15/3/2024`, `3:15`)
+    pub fn digits(&self) -> String {
+        match self {
+            DrillItem::Number { value, noun: None } => value.to_string(),
+            DrillItem::Number {
+                value,
+                noun: Some((singular, plural, _)),
+            } => format!("{} {}", value, if *value == 1 { singular } else { plural }),
+            DrillItem::Date { day, month, year } => format!("{}/{}/{}", day, month, year),
+            DrillItem::Time { hour, minute } => format!("{}:{:02}", hour, minute),
+        }
+    }
+
+    /// Accepted ways of saying the item, the usual one first
+    pub fn phrases(&self) -> Vec<String> {
+        match self {
+            DrillItem::Number { value, noun: None } => vec![number_to_words(*value, None)],
+            DrillItem::Number {
+                value,
+                noun: Some((singular, plural, gender)),
+            } => {
+                let noun = if *value == 1 { singular } else { plural };
+                let number = number_to_words(*value, Some(*gender));
+                // Round millions and billions take `de`, like other nouns
+                // of quantity: `un millón de personas`
+                let of = match number.rsplit(' ').next() {
+                    Some(
+                        "millón" | "millones" | "billón" | "billones" | "trillón" | "trillones",
+                    ) => " de",
+                    _ => "",
+                };
+                vec![format!("{}{} {}", number, of, noun)]
+            }
+            DrillItem::Date { day, month, year } => {
+                let date = date_to_words(*day, *month, *year);
+                let mut dates = vec![date.clone()];
+                if *day == 1 {
+                    dates.push(date.replacen("uno", "primero", 1));
+                }
+                // With or without the article
+                dates
+                    .into_iter()
+                    .flat_map(|date| [date.clone(), date["el ".len()..].to_string()])
+                    .collect()
+            }
+            DrillItem::Time { hour, minute } => time_phrases(*hour, *minute),
+        }
+    }
+
+    /// Whether an answer in digits matches (`15/3/2024`, `15-3-2024`, `3.15`)
+    pub fn check_digits(&self, answer: &str) -> bool {
+        let numbers: Vec<u64> = answer
+            .split(|c: char| !c.is_ascii_digit() && !matches!(c, '.' | ',' | ' '))
+            .flat_map(|part| match self {
+                // Thousands separators are fine in a single number
+                DrillItem::Number { .. } => vec![part.replace(['.', ',', ' '], "")],
+                _ => part
+                    .split(['.', ',', ' '])
+                    .map(str::to_string)
+                    .collect::<Vec<_>>(),
+            })
+            .filter_map(|part| part.parse().ok())
+            .collect();
+        match self {
+            DrillItem::Number { value, .. } => numbers == [*value],
+            DrillItem::Date { day, month, year } => {
+                numbers == [*day as u64, *month as u64, *year as u64]
+            }
+            DrillItem::Time { hour, minute } => {
+                numbers.len() == 2
+                    && numbers[0] % 12 == *hour as u64 % 12
+                    && numbers[1] == *minute as u64
+            }
+        }
+    }
+
+    /// Whether an answer in words matches, ignoring case and accents. Speech
+    /// recognition often writes numbers as digits, so dictated answers have
+    /// them turned into words first.
+    pub fn check_words(&self, answer: &str, dictated: bool) -> bool {
+        if dictated && self.check_digits(answer) {
+            return true;
+        }
+        let answer = if dictated {
+            answer
+                .split_whitespace()
+                .map(|word| match word.parse::<u64>() {
+                    Ok(n) => number_to_words(n, None),
+                    Err(_) => word.to_string(),
+                })
+                .collect::<Vec<_>>()
+                .join(" ")
+        } else {
+            answer.to_string()
+        };
+        let answer = normalize(&answer);
+        self.phrases().iter().any(|phrase| {
+            let phrase = normalize(phrase);
+            // Dictation can't tell uno from un or una
+            answer == phrase || (dictated && fold_uno(&answer) == fold_uno(&phrase))
+        })
+    }
+}
+
+fn fold_uno(text: &str) -> String {
+    text.split(' ')
+        .map(|word| match word {
+            "un" | "una" | "uno" => "uno",
+            "veintiun" | "veintiuna" | "veintiuno" => "veintiuno",
+            _ => word,
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(n: u64) -> String {
+        number_to_words(n, None)
+    }
+
+    #[test]
+    fn small_numbers() {
+        assert_eq!(words(0), "cero");
+        assert_eq!(words(16), "dieciséis");
+        assert_eq!(words(21), "veintiuno");
+        assert_eq!(words(31), "treinta y uno");
+        assert_eq!(words(100), "cien");
+        assert_eq!(words(101), "ciento uno");
+        assert_eq!(words(555), "quinientos cincuenta y cinco");
+    }
+
+    #[test]
+    fn large_numbers() {
+        assert_eq!(words(1000), "mil");
+        assert_eq!(words(2024), "dos mil veinticuatro");
+        assert_eq!(words(21_000), "veintiún mil");
+        assert_eq!(words(100_000), "cien mil");
+        assert_eq!(words(1_000_000), "un millón");
+        assert_eq!(words(2_500_000), "dos millones quinientos mil");
+        assert_eq!(words(1_000_000_000), "mil millones");
+        assert_eq!(words(3_000_000_000_000), "tres billones");
+        assert_eq!(words(1_000_000_000_000_000_000), "un trillón");
+        assert_eq!(
+            words(u64::MAX),
+            "dieciocho trillones \
+             cuatrocientos cuarenta y seis mil setecientos cuarenta y cuatro billones \
+             setenta y tres mil setecientos nueve millones \
+             quinientos cincuenta y un mil seiscientos quince"
+        );
+    }
+
+    #[test]
+    fn numbers_agree_with_their_noun() {
+        let feminine = Some(Gender::Feminine);
+        let masculine = Some(Gender::Masculine);
+        assert_eq!(number_to_words(1, feminine), "una");
+        assert_eq!(number_to_words(21, masculine), "veintiún");
+        assert_eq!(number_to_words(231, feminine), "doscientas treinta y una");
+        assert_eq!(number_to_words(231, masculine), "doscientos treinta y un");
+        assert_eq!(number_to_words(500_000, feminine), "quinientas mil");
+        assert_eq!(
+            number_to_words(200_000_000, feminine),
+            "doscientos millones"
+        );
+    }
+
+    #[test]
+    fn round_millions_take_de_before_the_noun() {
+        let item = |value| DrillItem::Number {
+            value,
+            noun: Some(("persona", "personas", Gender::Feminine)),
+        };
+        assert_eq!(item(1_000_000).phrases(), ["un millón de personas"]);
+        assert_eq!(item(3_000_000).phrases(), ["tres millones de personas"]);
+        assert_eq!(
+            item(2_000_000_000_000).phrases(),
+            ["dos billones de personas"]
+        );
+        assert_eq!(
+            item(1_200_000).phrases(),
+            ["un millón doscientas mil personas"]
+        );
+    }
+
+    #[test]
+    fn dates_and_times() {
+        assert_eq!(
+            date_to_words(15, 3, 2024),
+            "el quince de marzo de dos mil veinticuatro"
+        );
+        assert_eq!(time_phrases(1, 0)[0], "es la una");
+        assert_eq!(time_phrases(3, 15)[0], "son las tres y cuarto");
+        assert_eq!(time_phrases(15, 30)[0], "son las tres y media");
+        assert_eq!(time_phrases(12, 45)[0], "es la una menos cuarto");
+        assert_eq!(time_phrases(4, 40)[0], "son las cinco menos veinte");
+        assert_eq!(time_phrases(9, 21)[0], "son las nueve y veintiuno");
+    }
+
+    #[test]
+    fn answers_are_graded_both_ways() {
+        let date = DrillItem::Date {
+            day: 1,
+            month: 5,
+            year: 1999,
+        };
+        assert!(date.check_digits("1/5/1999"));
+        assert!(date.check_digits("1-5-1999"));
+        assert!(date.check_words("primero de mayo de mil novecientos noventa y nueve", false));
+
+        let number = DrillItem::Number {
+            value: 21_500,
+            noun: Some(("persona", "personas", Gender::Feminine)),
+        };
+        assert!(number.check_digits("21.500"));
+        assert!(number.check_words("veintiuna mil quinientas personas", false));
+        assert!(!number.check_words("veintiún mil quinientos personas", false));
+        assert!(number.check_words("21500 personas", true));
+
+        let time = DrillItem::Time {
+            hour: 15,
+            minute: 15,
+        };
+        assert!(time.check_digits("3:15"));
+        assert!(time.check_words("Son las tres y quince", false));
+        assert!(time.check_words("son las 3 y cuarto", true));
+    }
+}
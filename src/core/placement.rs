@@ -0,0 +1,191 @@
+//! Adaptive placement test: a binary search over the stages for the first
+//! one whose words the learner doesn't know yet.
+//!
+//! Each probed stage gets a few multiple choice questions. Passing it moves
+//! the search to later stages, failing it to earlier ones, so the whole test
+//! takes about five stages rather than all 21.
+
+use crate::core::text::normalize;
+use crate::data::{STAGE_COUNT, VocabularyCard};
+
+/// Questions asked about each probed stage
+pub const QUESTIONS_PER_STAGE: usize = 3;
+
+/// Right answers needed to pass a stage
+const PASS_MARK: usize = 2;
+
+/// Answer choices offered for each word
+const CHOICE_COUNT: usize = 4;
+
+/// Progress of a placement test
+#[derive(Debug, Clone, PartialEq)]
+pub struct Placement {
+    /// Every stage before `low` was passed
+    low: u32,
+    /// `high` was failed, or is the last stage
+    high: u32,
+    /// Right and wrong answers about the stage being probed
+    correct: usize,
+    wrong: usize,
+    /// Each stage probed so far, and whether it was passed
+    pub results: Vec<(u32, bool)>,
+}
+
+impl Default for Placement {
+    fn default() -> Self {
+        Self {
+            low: 1,
+            high: STAGE_COUNT,
+            correct: 0,
+            wrong: 0,
+            results: Vec::new(),
+        }
+    }
+}
+
+impl Placement {
+    /// Stage the next question is about, or `None` once the test is over
+    pub fn probe(&self) -> Option<u32> {
+        (self.low < self.high).then(|| (self.low + self.high) / 2)
+    }
+
+    /// Questions answered about the stage being probed
+    pub fn answered(&self) -> usize {
+        self.correct + self.wrong
+    }
+
+    /// Record an answer about the probed stage. The stage is decided as soon
+    /// as its outcome is certain, without asking the remaining questions.
+    pub fn answer(&mut self, correct: bool) {
+        let Some(stage) = self.probe() else {
+            return;
+        };
+        if correct {
+            self.correct += 1;
+        } else {
+            self.wrong += 1;
+        }
+        let passed = if self.correct >= PASS_MARK {
+            true
+        } else if self.wrong > QUESTIONS_PER_STAGE - PASS_MARK {
+            false
+        } else {
+            return;
+        };
+        if passed {
+            self.low = stage + 1;
+        } else {
+            self.high = stage;
+        }
+        self.correct = 0;
+        self.wrong = 0;
+        self.results.push((stage, passed));
+    }
+
+    /// Stage to start at, once the test is over
+    pub fn recommended(&self) -> Option<u32> {
+        self.probe().is_none().then_some(self.low)
+    }
+}
+
+/// A Spanish word and the English translations to choose from
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlacementQuestion {
+    /// Global card ID the word comes from
+    pub card_id: u32,
+    pub word: String,
+    pub answer: String,
+    /// The answer and translations of other words of the stage, in
+    /// alphabetical order
+    pub choices: Vec<String>,
+}
+
+/// Question about the card at `index` of a stage, given the stage's cards in
+/// both languages
+pub fn placement_question(
+    spanish: &[VocabularyCard],
+    english: &[VocabularyCard],
+    index: usize,
+) -> Option<PlacementQuestion> {
+    let (word, answer) = (spanish.get(index)?, english.get(index)?);
+    let mut choices = vec![answer.word.clone()];
+    // Step through the stage so neighbouring cards don't always appear together
+    let step = (english.len() / CHOICE_COUNT).max(1);
+    for offset in (1..english.len()).map(|i| (index + i * step) % english.len()) {
+        if choices.len() >= CHOICE_COUNT {
+            break;
+        }
+        let other = &english[offset].word;
+        if !choices.iter().any(|c| normalize(c) == normalize(other)) {
+            choices.push(other.clone());
+        }
+    }
+    choices.sort_by_key(|c| normalize(c));
+    Some(PlacementQuestion {
+        card_id: word.id,
+        word: word.word.clone(),
+        answer: answer.word.clone(),
+        choices,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::data::load_vocabulary_stage;
+
+    /// Take the test as a learner who knows every stage before `level`
+    fn take_test(level: u32) -> Placement {
+        let mut placement = Placement::default();
+        while let Some(stage) = placement.probe() {
+            placement.answer(stage < level);
+        }
+        placement
+    }
+
+    #[test]
+    fn finds_the_first_unknown_stage() {
+        for level in 1..=STAGE_COUNT {
+            assert_eq!(take_test(level).recommended(), Some(level));
+        }
+        // Knowing everything still starts at the last stage
+        assert_eq!(take_test(STAGE_COUNT + 1).recommended(), Some(STAGE_COUNT));
+    }
+
+    #[test]
+    fn probes_only_a_few_stages() {
+        for level in 1..=STAGE_COUNT {
+            assert!(take_test(level).results.len() <= 5);
+        }
+    }
+
+    #[test]
+    fn stages_are_decided_early() {
+        let mut placement = Placement::default();
+        let first = placement.probe();
+        placement.answer(true);
+        placement.answer(true);
+        assert_eq!(placement.results, [(11, true)]);
+        assert_ne!(placement.probe(), first);
+
+        placement.answer(true);
+        placement.answer(false);
+        assert_eq!(placement.answered(), 2);
+        placement.answer(false);
+        assert_eq!(placement.results.last(), Some(&(16, false)));
+    }
+
+    #[test]
+    fn questions_offer_distinct_choices() {
+        let spanish = load_vocabulary_stage(3, "es").unwrap();
+        let english = load_vocabulary_stage(3, "en").unwrap();
+        for index in 0..spanish.len() {
+            let question = placement_question(&spanish, &english, index).unwrap();
+            assert_eq!(question.choices.len(), CHOICE_COUNT);
+            assert!(question.choices.contains(&question.answer));
+            let mut distinct: Vec<String> = question.choices.iter().map(|c| normalize(c)).collect();
+            distinct.dedup();
+            assert_eq!(distinct.len(), CHOICE_COUNT);
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use crate::core::storage;
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+
+/// `localStorage` key holding the progress as JSON
+const STORAGE_KEY: &str = "vamos.progress";
+
+/// What the learner has worked on, kept across visits
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Progress {
+    /// Vocabulary stages the learner has opened
+    pub studied_stages: BTreeSet<u32>,
+}
+
+/// Global context for the learner's progress, saved on every change
+#[derive(Clone, Copy)]
+pub struct ProgressContext {
+    pub progress: RwSignal<Progress>,
+}
+
+impl ProgressContext {
+    pub fn new() -> Self {
+        let saved = storage::load(STORAGE_KEY)
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default();
+        Self {
+            progress: RwSignal::new(saved),
+        }
+    }
+
+    fn update(&self, change: impl FnOnce(&mut Progress)) {
+        self.progress.update(change);
+        if let Ok(json) = serde_json::to_string(&*self.progress.read_untracked()) {
+            storage::save(STORAGE_KEY, &json);
+        }
+    }
+
+    pub fn mark_studied(&self, stage: u32) {
+        if !self
+            .progress
+            .with_untracked(|p| p.studied_stages.contains(&stage))
+        {
+            self.update(|p| {
+                p.studied_stages.insert(stage);
+            });
+        }
+    }
+
+    pub fn studied_stages(&self) -> Vec<u32> {
+        self.progress
+            .read()
+            .studied_stages
+            .iter()
+            .copied()
+            .collect()
+    }
+}
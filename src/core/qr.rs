@@ -0,0 +1,564 @@
+//! Minimal QR code encoder (byte mode, error correction level M, versions 1-10).
+//!
+//! Enough to put a share link on screen for scanning, without pulling in a
+//! JavaScript library or a server.
+
+const MIN_VERSION: usize = 1;
+const MAX_VERSION: usize = 10;
+
+/// Error correction codewords per block for level M, indexed by version
+const ECC_CODEWORDS_PER_BLOCK: [usize; MAX_VERSION + 1] =
+    [0, 10, 16, 26, 18, 24, 16, 18, 22, 22, 26];
+
+/// Number of error correction blocks for level M, indexed by version
+const NUM_ERROR_CORRECTION_BLOCKS: [usize; MAX_VERSION + 1] = [0, 1, 1, 1, 2, 2, 4, 4, 4, 5, 5];
+
+/// Format bits identifying error correction level M
+const ECC_LEVEL_M_BITS: u32 = 0;
+
+/// A square grid of dark/light modules
+#[derive(Debug, Clone, PartialEq)]
+pub struct QrCode {
+    size: usize,
+    modules: Vec<Vec<bool>>,
+}
+
+impl QrCode {
+    /// Encode text as a QR code, choosing the smallest version that fits
+    pub fn encode_text(text: &str) -> Result<Self, String> {
+        let data = text.as_bytes();
+        let version = (MIN_VERSION..=MAX_VERSION)
+            .find(|v| byte_mode_bits(*v, data.len()) <= num_data_codewords(*v) * 8)
+            .ok_or_else(|| "Text is too long for a QR code".to_string())?;
+
+        let codewords = encode_data_codewords(version, data);
+        let mut builder = Builder::new(version);
+        builder.draw_function_patterns();
+        builder.draw_codewords(&add_ecc_and_interleave(version, &codewords));
+
+        // Pick the mask with the lowest penalty score
+        let mut best: Option<(u32, Vec<Vec<bool>>)> = None;
+        for mask in 0..8 {
+            let mut candidate = builder.clone();
+            candidate.apply_mask(mask);
+            candidate.draw_format_bits(mask);
+            let penalty = candidate.penalty_score();
+            if best.as_ref().is_none_or(|(score, _)| penalty < *score) {
+                best = Some((penalty, candidate.modules));
+            }
+        }
+
+        let (_, modules) = best.expect("at least one mask is evaluated");
+        Ok(Self {
+            size: builder.size,
+            modules,
+        })
+    }
+
+    /// Width and height in modules, excluding the quiet zone
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    /// Whether the module at column `x`, row `y` is dark
+    pub fn is_dark(&self, x: usize, y: usize) -> bool {
+        self.modules[y][x]
+    }
+}
+
+#[derive(Clone)]
+struct Builder {
+    version: usize,
+    size: usize,
+    modules: Vec<Vec<bool>>,
+    is_function: Vec<Vec<bool>>,
+}
+
+impl Builder {
+    fn new(version: usize) -> Self {
+        let size = version * 4 + 17;
+        Self {
+            version,
+            size,
+            modules: vec![vec![false; size]; size],
+            is_function: vec![vec![false; size]; size],
+        }
+    }
+
+    fn set_function_module(&mut self, x: usize, y: usize, dark: bool) {
+        self.modules[y][x] = dark;
+        self.is_function[y][x] = true;
+    }
+
+    fn draw_function_patterns(&mut self) {
+        // Timing patterns
+        for i in 0..self.size {
+            self.set_function_module(6, i, i % 2 == 0);
+            self.set_function_module(i, 6, i % 2 == 0);
+        }
+
+        // Finder patterns in three corners
+        self.draw_finder_pattern(3, 3);
+        self.draw_finder_pattern(self.size - 4, 3);
+        self.draw_finder_pattern(3, self.size - 4);
+
+        // Alignment patterns, skipping the ones overlapping finder patterns
+        let positions = alignment_pattern_positions(self.version);
+        let last = positions.len().saturating_sub(1);
+        for (i, x) in positions.iter().enumerate() {
+            for (j, y) in positions.iter().enumerate() {
+                let overlaps_finder = (i == 0 && (j == 0 || j == last)) || (i == last && j == 0);
+                if !overlaps_finder {
+                    self.draw_alignment_pattern(*x, *y);
+                }
+            }
+        }
+
+        // Reserve format areas, then draw version information
+        self.draw_format_bits(0);
+        self.draw_version();
+    }
+
+    fn draw_finder_pattern(&mut self, x: usize, y: usize) {
+        for dy in -4i32..=4 {
+            for dx in -4i32..=4 {
+                let xx = x as i32 + dx;
+                let yy = y as i32 + dy;
+                if (0..self.size as i32).contains(&xx) && (0..self.size as i32).contains(&yy) {
+                    let distance = dx.abs().max(dy.abs());
+                    self.set_function_module(
+                        xx as usize,
+                        yy as usize,
+                        distance != 2 && distance != 4,
+                    );
+                }
+            }
+        }
+    }
+
+    fn draw_alignment_pattern(&mut self, x: usize, y: usize) {
+        for dy in -2i32..=2 {
+            for dx in -2i32..=2 {
+                let distance = dx.abs().max(dy.abs());
+                self.set_function_module(
+                    (x as i32 + dx) as usize,
+                    (y as i32 + dy) as usize,
+                    distance != 1,
+                );
+            }
+        }
+    }
+
+    fn draw_format_bits(&mut self, mask: u32) {
+        let bits = format_bits(mask);
+        let size = self.size;
+
+        // First copy, around the top-left finder pattern
+        for i in 0..6 {
+            self.set_function_module(8, i, bit(bits, i));
+        }
+        self.set_function_module(8, 7, bit(bits, 6));
+        self.set_function_module(8, 8, bit(bits, 7));
+        self.set_function_module(7, 8, bit(bits, 8));
+        for i in 9..15 {
+            self.set_function_module(14 - i, 8, bit(bits, i));
+        }
+
+        // Second copy, split between the other two finder patterns
+        for i in 0..8 {
+            self.set_function_module(size - 1 - i, 8, bit(bits, i));
+        }
+        for i in 8..15 {
+            self.set_function_module(8, size - 15 + i, bit(bits, i));
+        }
+        self.set_function_module(8, size - 8, true);
+    }
+
+    fn draw_version(&mut self) {
+        if self.version < 7 {
+            return;
+        }
+        let bits = version_bits(self.version);
+        for i in 0..18 {
+            let a = self.size - 11 + i % 3;
+            let b = i / 3;
+            self.set_function_module(a, b, bit(bits, i));
+            self.set_function_module(b, a, bit(bits, i));
+        }
+    }
+
+    /// Place codewords in the zig-zag pattern, skipping function modules
+    fn draw_codewords(&mut self, data: &[u8]) {
+        let mut i = 0;
+        let mut right = self.size as i32 - 1;
+        while right >= 1 {
+            if right == 6 {
+                right = 5;
+            }
+            for vertical in 0..self.size {
+                for j in 0..2 {
+                    let x = (right - j) as usize;
+                    let upward = (right + 1) & 2 == 0;
+                    let y = if upward {
+                        self.size - 1 - vertical
+                    } else {
+                        vertical
+                    };
+                    if !self.is_function[y][x] && i < data.len() * 8 {
+                        self.modules[y][x] = (data[i / 8] >> (7 - i % 8)) & 1 == 1;
+                        i += 1;
+                    }
+                }
+            }
+            right -= 2;
+        }
+    }
+
+    fn apply_mask(&mut self, mask: u32) {
+        for y in 0..self.size {
+            for x in 0..self.size {
+                let invert = match mask {
+                    0 => (x + y) % 2 == 0,
+                    1 => y % 2 == 0,
+                    2 => x % 3 == 0,
+                    3 => (x + y) % 3 == 0,
+                    4 => (x / 3 + y / 2) % 2 == 0,
+                    5 => x * y % 2 + x * y % 3 == 0,
+                    6 => (x * y % 2 + x * y % 3) % 2 == 0,
+                    _ => ((x + y) % 2 + x * y % 3) % 2 == 0,
+                };
+                if invert && !self.is_function[y][x] {
+                    self.modules[y][x] = !self.modules[y][x];
+                }
+            }
+        }
+    }
+
+    fn penalty_score(&self) -> u32 {
+        let size = self.size;
+        let rows: Vec<Vec<bool>> = self.modules.clone();
+        let columns: Vec<Vec<bool>> = (0..size)
+            .map(|x| (0..size).map(|y| self.modules[y][x]).collect())
+            .collect();
+
+        let mut penalty = 0;
+        for line in rows.iter().chain(columns.iter()) {
+            penalty += run_penalty(line) + finder_like_penalty(line);
+        }
+
+        // 2x2 blocks of the same color
+        for y in 0..size - 1 {
+            for x in 0..size - 1 {
+                let color = self.modules[y][x];
+                if color == self.modules[y][x + 1]
+                    && color == self.modules[y + 1][x]
+                    && color == self.modules[y + 1][x + 1]
+                {
+                    penalty += 3;
+                }
+            }
+        }
+
+        // Balance of dark and light modules
+        let dark = self.modules.iter().flatten().filter(|m| **m).count();
+        let total = size * size;
+        let deviation = (dark * 20).abs_diff(total * 10);
+        penalty += (deviation.div_ceil(total) as u32).saturating_sub(1) * 10;
+        penalty
+    }
+}
+
+/// Runs of five or more modules of the same color
+fn run_penalty(line: &[bool]) -> u32 {
+    let mut penalty = 0;
+    let mut run_length = 1;
+    for i in 1..=line.len() {
+        if i < line.len() && line[i] == line[i - 1] {
+            run_length += 1;
+        } else {
+            if run_length >= 5 {
+                penalty += 3 + (run_length - 5) as u32;
+            }
+            run_length = 1;
+        }
+    }
+    penalty
+}
+
+/// Patterns resembling a finder pattern (1:1:3:1:1 with four light modules on one side)
+fn finder_like_penalty(line: &[bool]) -> u32 {
+    const PATTERN: [bool; 7] = [true, false, true, true, true, false, true];
+    let light = |start: i32, end: i32| {
+        (start..end).all(|i| i < 0 || i >= line.len() as i32 || !line[i as usize])
+    };
+
+    let mut penalty = 0;
+    for start in 0..line.len().saturating_sub(PATTERN.len() - 1) {
+        if line[start..start + PATTERN.len()] == PATTERN {
+            let start = start as i32;
+            let end = start + PATTERN.len() as i32;
+            if light(start - 4, start) || light(end, end + 4) {
+                penalty += 40;
+            }
+        }
+    }
+    penalty
+}
+
+/// The 15 format bits for level M and `mask`: a BCH(15, 5) code, masked
+fn format_bits(mask: u32) -> u32 {
+    let data = (ECC_LEVEL_M_BITS << 3) | mask;
+    let mut remainder = data;
+    for _ in 0..10 {
+        remainder = (remainder << 1) ^ ((remainder >> 9) * 0x537);
+    }
+    ((data << 10) | remainder) ^ 0x5412
+}
+
+/// The 18 version bits of versions 7 and up: a Golay(18, 6) code
+fn version_bits(version: usize) -> u32 {
+    let mut remainder = version as u32;
+    for _ in 0..12 {
+        remainder = (remainder << 1) ^ ((remainder >> 11) * 0x1f25);
+    }
+    ((version as u32) << 12) | remainder
+}
+
+fn bit(value: u32, index: usize) -> bool {
+    (value >> index) & 1 == 1
+}
+
+fn alignment_pattern_positions(version: usize) -> Vec<usize> {
+    if version == 1 {
+        return Vec::new();
+    }
+    let size = version * 4 + 17;
+    let count = version / 7 + 2;
+    let step = (version * 8 + count * 3 + 5) / (count * 4 - 4) * 2;
+    let mut positions: Vec<usize> = (0..count - 1).map(|i| size - 7 - i * step).collect();
+    positions.push(6);
+    positions.reverse();
+    positions
+}
+
+fn num_raw_data_modules(version: usize) -> usize {
+    let mut result = (16 * version + 128) * version + 64;
+    if version >= 2 {
+        let count = version / 7 + 2;
+        result -= (25 * count - 10) * count - 55;
+        if version >= 7 {
+            result -= 36;
+        }
+    }
+    result
+}
+
+fn num_data_codewords(version: usize) -> usize {
+    num_raw_data_modules(version) / 8
+        - ECC_CODEWORDS_PER_BLOCK[version] * NUM_ERROR_CORRECTION_BLOCKS[version]
+}
+
+fn char_count_bits(version: usize) -> usize {
+    if version <= 9 { 8 } else { 16 }
+}
+
+fn byte_mode_bits(version: usize, byte_count: usize) -> usize {
+    if byte_count >= 1 << char_count_bits(version) {
+        return usize::MAX;
+    }
+    4 + char_count_bits(version) + byte_count * 8
+}
+
+fn encode_data_codewords(version: usize, data: &[u8]) -> Vec<u8> {
+    let capacity_bits = num_data_codewords(version) * 8;
+    let mut bits: Vec<bool> = Vec::with_capacity(capacity_bits);
+    let mut push = |value: u32, length: usize| {
+        for shift in (0..length).rev() {
+            bits.push((value >> shift) & 1 == 1);
+        }
+    };
+
+    push(0b0100, 4);
+    push(data.len() as u32, char_count_bits(version));
+    for byte in data {
+        push(*byte as u32, 8);
+    }
+
+    // Terminator, then pad to a byte boundary
+    let terminator = (capacity_bits - bits.len()).min(4);
+    bits.extend(std::iter::repeat_n(false, terminator));
+    bits.extend(std::iter::repeat_n(false, (8 - bits.len() % 8) % 8));
+
+    let mut codewords: Vec<u8> = bits
+        .chunks(8)
+        .map(|chunk| chunk.iter().fold(0u8, |acc, b| (acc << 1) | *b as u8))
+        .collect();
+    for pad in [0xec, 0x11].into_iter().cycle() {
+        if codewords.len() >= capacity_bits / 8 {
+            break;
+        }
+        codewords.push(pad);
+    }
+    codewords
+}
+
+fn add_ecc_and_interleave(version: usize, data: &[u8]) -> Vec<u8> {
+    let num_blocks = NUM_ERROR_CORRECTION_BLOCKS[version];
+    let block_ecc_len = ECC_CODEWORDS_PER_BLOCK[version];
+    let raw_codewords = num_raw_data_modules(version) / 8;
+    let num_short_blocks = num_blocks - raw_codewords % num_blocks;
+    let short_block_len = raw_codewords / num_blocks;
+
+    let divisor = reed_solomon_divisor(block_ecc_len);
+    let mut blocks: Vec<Vec<u8>> = Vec::with_capacity(num_blocks);
+    let mut offset = 0;
+    for i in 0..num_blocks {
+        let data_len = short_block_len - block_ecc_len + usize::from(i >= num_short_blocks);
+        let mut block = data[offset..offset + data_len].to_vec();
+        offset += data_len;
+        let ecc = reed_solomon_remainder(&block, &divisor);
+        if i < num_short_blocks {
+            block.push(0);
+        }
+        block.extend(ecc);
+        blocks.push(block);
+    }
+
+    let mut result = Vec::with_capacity(raw_codewords);
+    for i in 0..blocks[0].len() {
+        for (j, block) in blocks.iter().enumerate() {
+            // Skip the padding byte inserted into short blocks
+            if i != short_block_len - block_ecc_len || j >= num_short_blocks {
+                result.push(block[i]);
+            }
+        }
+    }
+    result
+}
+
+fn reed_solomon_divisor(degree: usize) -> Vec<u8> {
+    let mut result = vec![0u8; degree - 1];
+    result.push(1);
+    let mut root = 1u8;
+    for _ in 0..degree {
+        for j in 0..degree {
+            result[j] = gf_multiply(result[j], root);
+            if j + 1 < degree {
+                result[j] ^= result[j + 1];
+            }
+        }
+        root = gf_multiply(root, 0x02);
+    }
+    result
+}
+
+fn reed_solomon_remainder(data: &[u8], divisor: &[u8]) -> Vec<u8> {
+    let mut result = vec![0u8; divisor.len()];
+    for byte in data {
+        let factor = byte ^ result.remove(0);
+        result.push(0);
+        for (r, d) in result.iter_mut().zip(divisor) {
+            *r ^= gf_multiply(*d, factor);
+        }
+    }
+    result
+}
+
+/// Multiplication in GF(2^8) modulo x^8 + x^4 + x^3 + x^2 + 1
+fn gf_multiply(x: u8, y: u8) -> u8 {
+    let mut z: u32 = 0;
+    for i in (0..8).rev() {
+        z = (z << 1) ^ ((z >> 7) * 0x11d);
+        z ^= ((y as u32 >> i) & 1) * x as u32;
+    }
+    z as u8
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Format bits read back from the copy around the top-left finder
+    fn read_format_bits(qr: &QrCode) -> u32 {
+        let mut positions: Vec<(usize, usize)> = (0..6).map(|i| (8, i)).collect();
+        positions.extend([(8, 7), (8, 8), (7, 8)]);
+        positions.extend((9..15).map(|i| (14 - i, 8)));
+        positions
+            .iter()
+            .enumerate()
+            .map(|(i, (x, y))| u32::from(qr.is_dark(*x, *y)) << i)
+            .sum()
+    }
+
+    #[test]
+    fn format_bits_match_the_standard_table() {
+        // Level M, masks 0 to 7 (ISO/IEC 18004, table C.1)
+        let expected = [
+            0b101010000010010,
+            0b101000100100101,
+            0b101111001111100,
+            0b101101101001011,
+            0b100010111111001,
+            0b100000011001110,
+            0b100111110010111,
+            0b100101010100000,
+        ];
+        for (mask, bits) in expected.into_iter().enumerate() {
+            assert_eq!(format_bits(mask as u32), bits, "mask {}", mask);
+        }
+        assert_eq!(version_bits(7), 0b000111110010010100);
+    }
+
+    #[test]
+    fn reed_solomon_matches_a_known_vector() {
+        // "HELLO WORLD" as 1-M alphanumeric data, from the thonky.com tutorial
+        let data = [
+            32, 91, 11, 120, 209, 114, 220, 77, 67, 64, 236, 17, 236, 17, 236, 17,
+        ];
+        let ecc = reed_solomon_remainder(&data, &reed_solomon_divisor(10));
+        assert_eq!(ecc, [196, 35, 39, 119, 235, 215, 231, 226, 93, 23]);
+    }
+
+    #[test]
+    fn short_text_fits_version_1() {
+        let qr = QrCode::encode_text("HELLO WORLD").unwrap();
+        assert_eq!(qr.size(), 21);
+
+        // Finder pattern rings in the three corners
+        for (x, y) in [(0, 0), (14, 0), (0, 14)] {
+            for dy in 0..7usize {
+                for dx in 0..7usize {
+                    let ring = dx.abs_diff(3).max(dy.abs_diff(3));
+                    assert_eq!(qr.is_dark(x + dx, y + dy), ring != 2);
+                }
+            }
+        }
+        // Timing patterns alternate between the finders
+        for i in 8..13 {
+            assert_eq!(qr.is_dark(i, 6), i % 2 == 0);
+            assert_eq!(qr.is_dark(6, i), i % 2 == 0);
+        }
+        // The dark module, and format bits for one of the masks
+        assert!(qr.is_dark(8, 13));
+        let format = read_format_bits(&qr);
+        assert!((0..8).any(|mask| format_bits(mask) == format));
+    }
+
+    #[test]
+    fn long_text_carries_version_bits() {
+        // 107 bytes no longer fit version 6 at level M
+        let qr = QrCode::encode_text(&"a".repeat(107)).unwrap();
+        assert_eq!(qr.size(), 7 * 4 + 17);
+        let bits = version_bits(7);
+        for i in 0..18 {
+            let (a, b) = (qr.size() - 11 + i % 3, i / 3);
+            assert_eq!(qr.is_dark(a, b), bit(bits, i));
+            assert_eq!(qr.is_dark(b, a), bit(bits, i));
+        }
+    }
+
+    #[test]
+    fn oversized_text_is_rejected() {
+        assert!(QrCode::encode_text(&"a".repeat(300)).is_err());
+    }
+}
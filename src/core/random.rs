@@ -0,0 +1,32 @@
+//! Random numbers for generated exercises, from the browser's `Math.random`.
+
+#[cfg(target_arch = "wasm32")]
+mod js {
+    use wasm_bindgen::prelude::*;
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = Math)]
+        pub fn random() -> f64;
+    }
+}
+
+/// A random number in `0..n`
+pub fn random_below(n: u64) -> u64 {
+    #[cfg(target_arch = "wasm32")]
+    let unit = js::random();
+    // Off the web, std's randomly seeded hasher is random enough
+    #[cfg(not(target_arch = "wasm32"))]
+    let unit = {
+        use std::hash::{BuildHasher, RandomState};
+        RandomState::new().hash_one(0u8) as f64 / u64::MAX as f64
+    };
+    ((unit * n as f64) as u64).min(n.saturating_sub(1))
+}
+
+/// Shuffle `items` in place
+pub fn shuffle<T>(items: &mut [T]) {
+    for i in (1..items.len()).rev() {
+        items.swap(i, random_below(i as u64 + 1) as usize);
+    }
+}
@@ -0,0 +1,98 @@
+//! Reminders of an unmet daily goal, through the Notifications API. The page
+//! schedules the next reminder and the service worker shows it, then sets
+//! it again for the next day.
+//!
+//! Browsers stop idle service workers after a few minutes, so the worker's
+//! timer only helps while the app is open. With the app closed, the reminder
+//! comes from periodic background sync, which only Chromium browsers offer,
+//! to installed apps, at an interval of their choosing: it may come late or
+//! not at all elsewhere.
+
+use crate::core::goals::next_reminder_day;
+use crate::core::{EventLogContext, SettingsContext, clock};
+use leptos::prelude::*;
+
+#[cfg(target_arch = "wasm32")]
+mod js {
+    use wasm_bindgen::prelude::*;
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = window)]
+        pub fn request_notifications(callback: &Closure<dyn Fn(bool)>);
+
+        #[wasm_bindgen(js_namespace = window)]
+        pub fn schedule_reminder(at: f64, body: &str, next_body: &str);
+
+        #[wasm_bindgen(js_namespace = window)]
+        pub fn cancel_reminder();
+    }
+}
+
+/// Ask for permission to show notifications, then call `on_answer` with
+/// whether it was granted
+pub fn request_permission(on_answer: impl Fn(bool) + 'static) {
+    #[cfg(target_arch = "wasm32")]
+    {
+        use wasm_bindgen::prelude::*;
+        let callback = Closure::<dyn Fn(bool)>::new(on_answer);
+        js::request_notifications(&callback);
+        // Called once the user answers, long after this returns
+        callback.forget();
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    on_answer(false);
+}
+
+/// Schedule a reminder with `body` at `at_ms`, repeated daily with
+/// `next_body` until the page schedules another
+fn schedule(at_ms: f64, body: &str, next_body: &str) {
+    #[cfg(target_arch = "wasm32")]
+    js::schedule_reminder(at_ms, body, next_body);
+    #[cfg(not(target_arch = "wasm32"))]
+    let _ = (at_ms, body, next_body);
+}
+
+fn cancel() {
+    #[cfg(target_arch = "wasm32")]
+    js::cancel_reminder();
+}
+
+/// Keep the next goal reminder scheduled as the settings and today's
+/// progress change
+pub fn use_goal_reminders() {
+    let settings_ctx = expect_context::<SettingsContext>();
+    let events_ctx = expect_context::<EventLogContext>();
+
+    let next = Memo::new(move |_| {
+        let settings = settings_ctx.settings.read();
+        let hour = settings.reminder_hour?;
+        let goal = settings.daily_goal;
+        let today = clock::today();
+        let progress = goal.progress(&events_ctx.events.read(), today);
+        let day = next_reminder_day(clock::now_ms(), today, hour, progress >= goal.target);
+        let remaining = if day == today {
+            goal.target - progress
+        } else {
+            goal.target
+        };
+        let body = |remaining: u32| {
+            format!(
+                "{} {} to go for today's goal. ¡Vamos!",
+                remaining,
+                goal.unit.name()
+            )
+        };
+        Some((
+            clock::local_time_ms(day, hour),
+            body(remaining),
+            // Days the app isn't opened start from nothing
+            body(goal.target),
+        ))
+    });
+
+    Effect::new(move |_| match next.get() {
+        Some((at, body, next_body)) => schedule(at, &body, &next_body),
+        None => cancel(),
+    });
+}
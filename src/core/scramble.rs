@@ -0,0 +1,156 @@
+//! Word-order exercises built from card example sentences.
+//!
+//! The sentence is cut into word chips; punctuation and the inverted marks
+//! `¿ ¡` are dropped from the chips since they give the order away. The
+//! contractions `al` and `del` stay single chips, as `a el` and `de el`
+//! aren't written in Spanish.
+
+use crate::core::text::shuffle_key;
+use crate::data::VocabularyCard;
+
+/// Fewest words worth scrambling
+const MIN_WORDS: usize = 3;
+
+/// An example sentence cut into chips to put back in order
+#[derive(Debug, Clone, PartialEq)]
+pub struct Scramble {
+    /// Global card ID the sentence comes from
+    pub card_id: u32,
+    /// The sentence as written
+    pub sentence: String,
+    /// The example in the other language, shown as the prompt
+    pub prompt: String,
+    /// The words of the sentence, shuffled
+    pub chips: Vec<String>,
+    /// Word sequences accepted as answers: the sentence and its alternatives
+    accepted: Vec<Vec<String>>,
+}
+
+impl Scramble {
+    /// Whether the chips picked in this order rebuild the sentence or one of
+    /// its alternative orders. Case is ignored, since moving a word to the
+    /// start of the sentence capitalizes it.
+    pub fn check(&self, order: &[usize]) -> bool {
+        let answer: Vec<String> = order
+            .iter()
+            .filter_map(|&i| self.chips.get(i))
+            .map(|chip| chip.to_lowercase())
+            .collect();
+        self.accepted.iter().any(|words| {
+            words.len() == answer.len()
+                && words
+                    .iter()
+                    .zip(&answer)
+                    .all(|(w, a)| w.to_lowercase() == *a)
+        })
+    }
+}
+
+/// Cut a sentence into words, without punctuation
+pub fn tokenize(sentence: &str) -> Vec<String> {
+    sentence
+        .split(|c: char| c.is_whitespace() || c == '—')
+        .map(|token| token.trim_matches(|c: char| !c.is_alphanumeric()))
+        .filter(|token| !token.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Scramble the example of `target`, with the `source` example as the
+/// prompt. Returns `None` when the sentence is too short to scramble.
+pub fn generate_scramble(target: &VocabularyCard, source: &VocabularyCard) -> Option<Scramble> {
+    let words = tokenize(&target.example);
+    if words.len() < MIN_WORDS {
+        return None;
+    }
+
+    let mut chips: Vec<(usize, String)> = words.iter().cloned().enumerate().collect();
+    chips.sort_by_key(|(i, word)| shuffle_key(&format!("{}{}", i, word)));
+    // Never hand out the chips already in order
+    if chips.iter().map(|(_, w)| w).eq(words.iter()) {
+        chips.rotate_left(1);
+    }
+
+    let mut accepted = vec![words];
+    accepted.extend(
+        target
+            .alternatives
+            .iter()
+            .map(|alternative| tokenize(alternative)),
+    );
+    Some(Scramble {
+        card_id: target.id,
+        sentence: target.example.clone(),
+        prompt: source.example.clone(),
+        chips: chips.into_iter().map(|(_, word)| word).collect(),
+        accepted,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn card(example: &str, alternatives: &[&str]) -> VocabularyCard {
+        VocabularyCard {
+            id: 1,
+            word: String::new(),
+            example: example.to_string(),
+            alternatives: alternatives.iter().map(|a| a.to_string()).collect(),
+        }
+    }
+
+    /// Chip indices that spell out `words`
+    fn order(scramble: &Scramble, words: &str) -> Vec<usize> {
+        let mut used = vec![false; scramble.chips.len()];
+        tokenize(words)
+            .iter()
+            .map(|word| {
+                let i = (0..scramble.chips.len())
+                    .find(|&i| !used[i] && scramble.chips[i].eq_ignore_ascii_case(word))
+                    .unwrap();
+                used[i] = true;
+                i
+            })
+            .collect()
+    }
+
+    #[test]
+    fn punctuation_is_dropped_and_contractions_kept() {
+        assert_eq!(
+            tokenize("¿Vas al mercado del barrio, Ana?"),
+            ["Vas", "al", "mercado", "del", "barrio", "Ana"]
+        );
+        assert_eq!(tokenize("¡Hola!"), ["Hola"]);
+    }
+
+    #[test]
+    fn chips_are_shuffled() {
+        let scramble =
+            generate_scramble(&card("La casa está cerca del parque", &[]), &card("", &[])).unwrap();
+        assert_ne!(scramble.chips, tokenize(&scramble.sentence));
+        let mut sorted = scramble.chips.clone();
+        sorted.sort();
+        let mut words = tokenize(&scramble.sentence);
+        words.sort();
+        assert_eq!(sorted, words);
+    }
+
+    #[test]
+    fn alternative_orders_are_accepted() {
+        let target = card("Hace mal tiempo hoy", &["Hoy hace mal tiempo"]);
+        let scramble = generate_scramble(&target, &card("", &[])).unwrap();
+        assert!(scramble.check(&order(&scramble, "Hace mal tiempo hoy")));
+        assert!(scramble.check(&order(&scramble, "hoy Hace mal tiempo")));
+        assert!(!scramble.check(&order(&scramble, "Mal hace tiempo hoy")));
+        assert!(!scramble.check(&order(&scramble, "Hace mal tiempo")));
+    }
+
+    #[test]
+    fn short_sentences_are_skipped() {
+        assert_eq!(
+            generate_scramble(&card("¡Muy bien!", &[]), &card("", &[])),
+            None
+        );
+    }
+}
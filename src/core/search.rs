@@ -0,0 +1,333 @@
+//! Vocabulary search across all stages and both languages.
+//!
+//! Matching is accent-insensitive (`manana` finds `mañana`), ignores articles
+//! (`agua` finds `el agua`) and accepts prefixes and small typos.
+
+use crate::core::headword::is_article;
+use crate::core::text::{edit_distance, normalize};
+use crate::data::{CatalogueEntry, DataError, load_catalogue};
+use std::sync::OnceLock;
+
+const EXACT_SCORE: u32 = 3;
+const PREFIX_SCORE: u32 = 2;
+const FUZZY_SCORE: u32 = 1;
+
+/// Extra score when the whole headword equals the query
+const PHRASE_BONUS: u32 = 10;
+
+/// Which part of a card a query matched
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MatchedField {
+    SpanishWord,
+    EnglishWord,
+    SpanishExample,
+    EnglishExample,
+}
+
+impl MatchedField {
+    fn is_word(self) -> bool {
+        matches!(self, MatchedField::SpanishWord | MatchedField::EnglishWord)
+    }
+}
+
+/// A card matching a search query
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchResult {
+    pub stage: u32,
+    pub card_id: u32,
+    pub spanish_word: String,
+    pub english_word: String,
+    pub matched_field: MatchedField,
+    /// The matching example sentence, when the match was in an example
+    pub example: Option<String>,
+    pub score: u32,
+}
+
+struct IndexedField {
+    field: MatchedField,
+    text: String,
+    terms: Vec<String>,
+    phrase: String,
+}
+
+struct Document {
+    entry: CatalogueEntry,
+    fields: Vec<IndexedField>,
+}
+
+/// Search index over every card in the catalogue
+pub struct SearchIndex {
+    documents: Vec<Document>,
+}
+
+/// Get the shared search index, building it on first use
+pub fn search_index() -> Result<&'static SearchIndex, DataError> {
+    static INDEX: OnceLock<Result<SearchIndex, DataError>> = OnceLock::new();
+    INDEX
+        .get_or_init(|| load_catalogue().map(|entries| SearchIndex::new(&entries)))
+        .as_ref()
+        .map_err(Clone::clone)
+}
+
+impl SearchIndex {
+    /// Build an index over the given catalogue entries
+    pub fn new(entries: &[CatalogueEntry]) -> Self {
+        let documents = entries
+            .iter()
+            .map(|entry| Document {
+                entry: entry.clone(),
+                fields: vec![
+                    index_word(MatchedField::SpanishWord, &entry.spanish.word),
+                    index_word(MatchedField::EnglishWord, &entry.english.word),
+                    index_example(MatchedField::SpanishExample, &entry.spanish.example),
+                    index_example(MatchedField::EnglishExample, &entry.english.example),
+                ],
+            })
+            .collect();
+        Self { documents }
+    }
+
+    /// Find cards matching the query, best matches first
+    pub fn search(&self, query: &str, limit: usize) -> Vec<SearchResult> {
+        let tokens = query_tokens(query);
+        if tokens.is_empty() {
+            return Vec::new();
+        }
+        let phrase = tokens.join(" ");
+
+        let mut results: Vec<SearchResult> = self
+            .documents
+            .iter()
+            .filter_map(|document| {
+                let (score, field) = document
+                    .fields
+                    .iter()
+                    .filter_map(|field| score_field(field, &tokens, &phrase).map(|s| (s, field)))
+                    .max_by_key(|(score, _)| *score)?;
+                Some(SearchResult {
+                    stage: document.entry.stage,
+                    card_id: document.entry.spanish.id,
+                    spanish_word: document.entry.spanish.word.clone(),
+                    english_word: document.entry.english.word.clone(),
+                    matched_field: field.field,
+                    example: (!field.field.is_word()).then(|| field.text.clone()),
+                    score,
+                })
+            })
+            .collect();
+
+        results.sort_by(|a, b| b.score.cmp(&a.score).then(a.card_id.cmp(&b.card_id)));
+        results.truncate(limit);
+        results
+    }
+}
+
+/// Normalized query words, without articles unless the query is only articles
+fn query_tokens(query: &str) -> Vec<String> {
+    let normalized = normalize(query);
+    let words: Vec<&str> = normalized.split_whitespace().collect();
+    let content: Vec<&str> = words.iter().copied().filter(|w| !is_article(w)).collect();
+    let chosen = if content.is_empty() { words } else { content };
+    chosen.into_iter().map(str::to_string).collect()
+}
+
+/// Index a headword, expanding slash notation (`amigo/a`, `much/many`)
+fn index_word(field: MatchedField, word: &str) -> IndexedField {
+    let mut terms = Vec::new();
+    for chunk in word.split_whitespace() {
+        let mut parts = chunk.split('/');
+        let Some(base) = parts.next() else { continue };
+        terms.push(normalize(base));
+        // Only words take endings: `el/la` is two articles, not `ella`
+        let takes_endings = !is_article(&normalize(base));
+        for part in parts {
+            let variant = match (base.strip_suffix('o'), part.chars().count()) {
+                _ if !takes_endings => part.to_string(),
+                (Some(stem), 1..=2) => format!("{}{}", stem, part),
+                (None, 1..=2) => format!("{}{}", base, part),
+                _ => part.to_string(),
+            };
+            terms.push(normalize(&variant));
+        }
+    }
+    terms.retain(|term| !term.is_empty() && !is_article(term));
+
+    let phrase = normalize(word)
+        .split_whitespace()
+        .filter(|w| !is_article(w))
+        .collect::<Vec<_>>()
+        .join(" ");
+    IndexedField {
+        field,
+        text: word.to_string(),
+        terms,
+        phrase,
+    }
+}
+
+fn index_example(field: MatchedField, example: &str) -> IndexedField {
+    let normalized = normalize(example);
+    IndexedField {
+        field,
+        text: example.to_string(),
+        terms: normalized.split_whitespace().map(str::to_string).collect(),
+        phrase: normalized,
+    }
+}
+
+/// Score a field, requiring every query word to match one of its terms
+fn score_field(field: &IndexedField, tokens: &[String], phrase: &str) -> Option<u32> {
+    let is_word = field.field.is_word();
+    let mut total = 0;
+    for token in tokens {
+        let best = field
+            .terms
+            .iter()
+            .map(|term| score_term(token, term, is_word))
+            .max()
+            .unwrap_or(0);
+        if best == 0 {
+            return None;
+        }
+        total += best;
+    }
+
+    if is_word {
+        let bonus = if field.phrase == phrase {
+            PHRASE_BONUS
+        } else {
+            0
+        };
+        Some(total * 3 + bonus)
+    } else {
+        Some(total)
+    }
+}
+
+/// Exact, prefix or (for headwords only) fuzzy match of a query word
+fn score_term(token: &str, term: &str, allow_fuzzy: bool) -> u32 {
+    let length = token.chars().count();
+    if token == term {
+        return EXACT_SCORE;
+    }
+    let min_prefix = if allow_fuzzy { 2 } else { 3 };
+    if length >= min_prefix && term.starts_with(token) {
+        return PREFIX_SCORE;
+    }
+    let max_typos = match length {
+        0..=3 => 0,
+        4..=6 => 1,
+        _ => 2,
+    };
+    if allow_fuzzy && max_typos > 0 && edit_distance(token, term) <= max_typos {
+        return FUZZY_SCORE;
+    }
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn search(query: &str) -> Vec<SearchResult> {
+        search_index().unwrap().search(query, 50)
+    }
+
+    fn finds(query: &str, spanish_word: &str) -> bool {
+        search(query).iter().any(|r| r.spanish_word == spanish_word)
+    }
+
+    #[test]
+    fn index_covers_every_card() {
+        let index = search_index().unwrap();
+        assert_eq!(index.documents.len(), 420);
+    }
+
+    #[test]
+    fn empty_query_has_no_results() {
+        assert!(search("").is_empty());
+        assert!(search("  ¿? ").is_empty());
+    }
+
+    #[test]
+    fn accents_are_folded() {
+        assert!(finds("manana", "mañana"));
+        assert!(finds("arbol", "el árbol"));
+        assert!(finds("MAÑANA", "mañana"));
+    }
+
+    #[test]
+    fn articles_are_ignored() {
+        let results = search("agua");
+        assert_eq!(results[0].spanish_word, "el agua");
+        assert_eq!(results[0].matched_field, MatchedField::SpanishWord);
+        assert!(finds("el agua", "el agua"));
+        assert!(finds("the water", "el agua"));
+    }
+
+    #[test]
+    fn gendered_forms_are_indexed() {
+        assert!(finds("amiga", "el/la amigo/a"));
+        assert!(finds("profesora", "el/la profesor/a"));
+        assert!(finds("many", "mucho/a"));
+    }
+
+    #[test]
+    fn paired_articles_are_not_a_word() {
+        // `el/la amigo/a` must not index `ella`
+        assert!(
+            !search("ella")
+                .iter()
+                .any(|r| r.spanish_word.starts_with("el/la")
+                    && r.matched_field == MatchedField::SpanishWord)
+        );
+    }
+
+    #[test]
+    fn prefixes_match_headwords() {
+        assert!(finds("herm", "el/la hermano/a"));
+        assert!(finds("bicy", "la bicicleta"));
+    }
+
+    #[test]
+    fn typos_are_tolerated() {
+        assert!(finds("bicicelta", "la bicicleta"));
+        assert!(finds("hemano", "el/la hermano/a"));
+        assert!(!finds("sol", "la sala"));
+    }
+
+    #[test]
+    fn examples_are_searched() {
+        let results = search("parque");
+        let house = results
+            .iter()
+            .find(|r| r.spanish_word == "la casa")
+            .expect("example match");
+        assert_eq!(house.matched_field, MatchedField::SpanishExample);
+        assert_eq!(
+            house.example.as_deref(),
+            Some("La casa está cerca del parque")
+        );
+    }
+
+    #[test]
+    fn headword_matches_rank_above_examples() {
+        let results = search("parque");
+        assert_eq!(results[0].spanish_word, "el parque");
+    }
+
+    #[test]
+    fn repeated_words_are_found_in_every_stage() {
+        let stages: Vec<u32> = search("tiempo")
+            .iter()
+            .filter(|r| r.spanish_word == "el tiempo")
+            .map(|r| r.stage)
+            .collect();
+        assert_eq!(stages, vec![1, 5, 16]);
+    }
+
+    #[test]
+    fn results_are_limited() {
+        assert!(search_index().unwrap().search("e", 5).len() <= 5);
+    }
+}
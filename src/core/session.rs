@@ -0,0 +1,163 @@
+//! What happened during one pass through a stage's cards, for the summary
+//! shown at the end of it.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Statistics of a study session, keyed by card index within the stage
+#[derive(Debug, Clone, PartialEq)]
+pub struct SessionStats {
+    started_ms: f64,
+    ended_ms: Option<f64>,
+    seen: BTreeSet<usize>,
+    /// Cards whose translation was shown without looking at the example
+    revealed_without_example: BTreeSet<usize>,
+    /// Global IDs of the cards made favorites
+    favorites_added: BTreeSet<u32>,
+    /// The last grade given to each card: known (`true`) or missed
+    grades: BTreeMap<usize, bool>,
+}
+
+impl SessionStats {
+    pub fn new(now_ms: f64) -> Self {
+        Self {
+            started_ms: now_ms,
+            ended_ms: None,
+            seen: BTreeSet::new(),
+            revealed_without_example: BTreeSet::new(),
+            favorites_added: BTreeSet::new(),
+            grades: BTreeMap::new(),
+        }
+    }
+
+    pub fn see(&mut self, index: usize) {
+        self.seen.insert(index);
+    }
+
+    pub fn reveal(&mut self, index: usize, example_shown: bool) {
+        if !example_shown {
+            self.revealed_without_example.insert(index);
+        }
+    }
+
+    /// A favorite toggled on (`added`) or back off
+    pub fn favorite(&mut self, card_id: u32, added: bool) {
+        if added {
+            self.favorites_added.insert(card_id);
+        } else {
+            self.favorites_added.remove(&card_id);
+        }
+    }
+
+    pub fn grade(&mut self, index: usize, known: bool) {
+        self.grades.insert(index, known);
+    }
+
+    pub fn finish(&mut self, now_ms: f64) {
+        self.ended_ms.get_or_insert(now_ms);
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.ended_ms.is_some()
+    }
+
+    /// Time from the start to the end of the session, or to `now_ms` if it
+    /// hasn't ended
+    pub fn duration_ms(&self, now_ms: f64) -> f64 {
+        (self.ended_ms.unwrap_or(now_ms) - self.started_ms).max(0.0)
+    }
+
+    pub fn seen_count(&self) -> usize {
+        self.seen.len()
+    }
+
+    pub fn revealed_without_example_count(&self) -> usize {
+        self.revealed_without_example.len()
+    }
+
+    pub fn favorites_added_count(&self) -> usize {
+        self.favorites_added.len()
+    }
+
+    /// Percentage of graded cards that were known, if any were graded
+    pub fn accuracy(&self) -> Option<u32> {
+        if self.grades.is_empty() {
+            return None;
+        }
+        let known = self.grades.values().filter(|known| **known).count();
+        Some((known * 100 / self.grades.len()) as u32)
+    }
+
+    /// Indices of the cards graded as missed, in stage order
+    pub fn missed(&self) -> Vec<usize> {
+        self.grades
+            .iter()
+            .filter(|(_, known)| !**known)
+            .map(|(index, _)| *index)
+            .collect()
+    }
+}
+
+/// A duration as minutes and seconds, e.g. "4 min 05 s"
+pub fn format_duration(ms: f64) -> String {
+    let seconds = (ms / 1000.0).round() as u64;
+    if seconds < 60 {
+        format!("{} s", seconds)
+    } else {
+        format!("{} min {:02} s", seconds / 60, seconds % 60)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn regrading_a_card_keeps_the_last_grade() {
+        let mut stats = SessionStats::new(0.0);
+        assert_eq!(stats.accuracy(), None);
+        stats.grade(0, true);
+        stats.grade(1, false);
+        stats.grade(2, false);
+        stats.grade(2, true);
+        stats.grade(3, true);
+        assert_eq!(stats.accuracy(), Some(75));
+        assert_eq!(stats.missed(), [1]);
+    }
+
+    #[test]
+    fn only_reveals_without_the_example_count() {
+        let mut stats = SessionStats::new(0.0);
+        stats.see(0);
+        stats.see(1);
+        stats.see(0);
+        stats.reveal(0, false);
+        stats.reveal(1, true);
+        assert_eq!(stats.seen_count(), 2);
+        assert_eq!(stats.revealed_without_example_count(), 1);
+    }
+
+    #[test]
+    fn unfavoriting_takes_a_favorite_back() {
+        let mut stats = SessionStats::new(0.0);
+        stats.favorite(21, true);
+        stats.favorite(22, true);
+        stats.favorite(21, false);
+        assert_eq!(stats.favorites_added_count(), 1);
+    }
+
+    #[test]
+    fn the_clock_stops_at_the_end() {
+        let mut stats = SessionStats::new(1_000.0);
+        assert_eq!(stats.duration_ms(4_000.0), 3_000.0);
+        stats.finish(6_000.0);
+        stats.finish(9_000.0);
+        assert!(stats.is_finished());
+        assert_eq!(stats.duration_ms(60_000.0), 5_000.0);
+    }
+
+    #[test]
+    fn durations_read_as_minutes_and_seconds() {
+        assert_eq!(format_duration(42_400.0), "42 s");
+        assert_eq!(format_duration(245_000.0), "4 min 05 s");
+    }
+}
@@ -0,0 +1,48 @@
+use crate::core::goals::DailyGoal;
+use crate::core::ipa::Dialect;
+use crate::core::mastery::MixedOrder;
+use crate::core::storage;
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// `localStorage` key holding the settings as JSON
+const STORAGE_KEY: &str = "vamos.settings";
+
+/// User preferences, kept across visits
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    /// Accent used for IPA transcriptions
+    pub dialect: Dialect,
+    /// Show IPA transcriptions of Spanish words and examples on cards
+    pub show_ipa: bool,
+    /// How sessions in both directions pick each card's direction
+    pub mixed_order: MixedOrder,
+    pub daily_goal: DailyGoal,
+    /// Hour of the day to be reminded of an unmet goal, if reminders are on
+    pub reminder_hour: Option<u32>,
+}
+
+/// Global context for the user's settings, saved on every change
+#[derive(Clone, Copy)]
+pub struct SettingsContext {
+    pub settings: RwSignal<Settings>,
+}
+
+impl SettingsContext {
+    pub fn new() -> Self {
+        let saved = storage::load(STORAGE_KEY)
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default();
+        Self {
+            settings: RwSignal::new(saved),
+        }
+    }
+
+    pub fn update(&self, change: impl FnOnce(&mut Settings)) {
+        self.settings.update(change);
+        if let Ok(json) = serde_json::to_string(&*self.settings.read_untracked()) {
+            storage::save(STORAGE_KEY, &json);
+        }
+    }
+}
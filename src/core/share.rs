@@ -0,0 +1,196 @@
+//! Compact, URL-safe encoding of a favorites list.
+//!
+//! Card IDs are sorted, delta-encoded and written as Elias gamma codes into a
+//! bit stream, which is then base64url encoded without padding. Stages are not
+//! stored since every global card ID belongs to exactly one stage.
+
+use crate::data::stage_for_card_id;
+
+/// Format version written as the first byte of every share code
+const SHARE_FORMAT_VERSION: u8 = 1;
+
+const BASE64URL_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Encode favorite (stage, card_id) pairs into a share code
+pub fn encode_favorites(favorites: &[(u32, u32)]) -> String {
+    let mut ids: Vec<u32> = favorites.iter().map(|(_, card_id)| *card_id).collect();
+    ids.sort_unstable();
+    ids.dedup();
+
+    let mut writer = BitWriter::default();
+    let mut previous = 0;
+    for id in ids.into_iter().filter(|id| *id > 0) {
+        writer.write_gamma(id - previous);
+        previous = id;
+    }
+
+    let mut bytes = vec![SHARE_FORMAT_VERSION];
+    bytes.extend(writer.finish());
+    base64url_encode(&bytes)
+}
+
+/// Decode a share code back into sorted (stage, card_id) pairs
+pub fn decode_favorites(code: &str) -> Result<Vec<(u32, u32)>, String> {
+    let bytes = base64url_decode(code.trim())?;
+    let (version, payload) = bytes
+        .split_first()
+        .ok_or_else(|| "Share code is empty".to_string())?;
+    if *version != SHARE_FORMAT_VERSION {
+        return Err(format!("Unsupported share code version {}", version));
+    }
+
+    let mut reader = BitReader::new(payload);
+    let mut favorites = Vec::new();
+    let mut card_id = 0u32;
+    while let Some(delta) = reader.read_gamma() {
+        card_id = card_id
+            .checked_add(delta)
+            .ok_or_else(|| "Share code contains an invalid card".to_string())?;
+        let stage = stage_for_card_id(card_id)
+            .ok_or_else(|| format!("Share code contains unknown card {}", card_id))?;
+        favorites.push((stage, card_id));
+    }
+    Ok(favorites)
+}
+
+#[derive(Default)]
+struct BitWriter {
+    bytes: Vec<u8>,
+    bit_len: usize,
+}
+
+impl BitWriter {
+    fn write_bit(&mut self, bit: bool) {
+        if self.bit_len.is_multiple_of(8) {
+            self.bytes.push(0);
+        }
+        if bit {
+            let last = self.bytes.len() - 1;
+            self.bytes[last] |= 0x80 >> (self.bit_len % 8);
+        }
+        self.bit_len += 1;
+    }
+
+    /// Elias gamma code: N zero bits followed by the N+1 significant bits of value
+    fn write_gamma(&mut self, value: u32) {
+        let significant_bits = 32 - value.leading_zeros();
+        for _ in 1..significant_bits {
+            self.write_bit(false);
+        }
+        for shift in (0..significant_bits).rev() {
+            self.write_bit((value >> shift) & 1 == 1);
+        }
+    }
+
+    fn finish(self) -> Vec<u8> {
+        self.bytes
+    }
+}
+
+struct BitReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> BitReader<'a> {
+    fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    fn read_bit(&mut self) -> Option<bool> {
+        let byte = self.bytes.get(self.position / 8)?;
+        let bit = byte & (0x80 >> (self.position % 8)) != 0;
+        self.position += 1;
+        Some(bit)
+    }
+
+    /// Returns `None` once only zero padding is left in the stream
+    fn read_gamma(&mut self) -> Option<u32> {
+        let mut zeros = 0;
+        while !self.read_bit()? {
+            zeros += 1;
+            if zeros >= 32 {
+                return None;
+            }
+        }
+        let mut value = 1u32;
+        for _ in 0..zeros {
+            value = (value << 1) | self.read_bit()? as u32;
+        }
+        Some(value)
+    }
+}
+
+fn base64url_encode(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let buffer = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |acc, (i, byte)| acc | (*byte as u32) << (16 - 8 * i));
+        for i in 0..=chunk.len() {
+            let index = (buffer >> (18 - 6 * i)) & 0x3f;
+            out.push(BASE64URL_ALPHABET[index as usize] as char);
+        }
+    }
+    out
+}
+
+fn base64url_decode(text: &str) -> Result<Vec<u8>, String> {
+    let mut bytes = Vec::with_capacity(text.len() * 3 / 4);
+    let mut buffer = 0u32;
+    let mut bits = 0;
+    for c in text.trim_end_matches('=').bytes() {
+        let value = BASE64URL_ALPHABET
+            .iter()
+            .position(|a| *a == c)
+            .ok_or_else(|| "Share code contains invalid characters".to_string())?;
+        buffer = (buffer << 6) | value as u32;
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            bytes.push((buffer >> bits) as u8);
+        }
+    }
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn favorites_survive_a_round_trip() {
+        for favorites in [
+            vec![],
+            vec![(3, 57)],
+            // The largest gap there can be, and neighbours
+            vec![(1, 1), (1, 2), (21, 420)],
+        ] {
+            let code = encode_favorites(&favorites);
+            assert_eq!(decode_favorites(&code), Ok(favorites));
+        }
+    }
+
+    #[test]
+    fn encoding_sorts_and_drops_duplicates() {
+        let code = encode_favorites(&[(2, 30), (1, 4), (2, 30)]);
+        assert_eq!(decode_favorites(&code), Ok(vec![(1, 4), (2, 30)]));
+    }
+
+    #[test]
+    fn malformed_codes_are_rejected() {
+        assert!(decode_favorites("").is_err());
+        assert!(decode_favorites("AQ$").is_err());
+        // Version 2
+        assert!(decode_favorites(&base64url_encode(&[2])).is_err());
+
+        // A card past the last stage
+        let mut writer = BitWriter::default();
+        writer.write_gamma(421);
+        let mut bytes = vec![SHARE_FORMAT_VERSION];
+        bytes.extend(writer.finish());
+        assert!(decode_favorites(&base64url_encode(&bytes)).is_err());
+    }
+}
@@ -0,0 +1,47 @@
+//! Text-to-speech and speech recognition through the Web Speech API helpers
+//! in `index.html`. Off the web both do nothing.
+
+#[cfg(target_arch = "wasm32")]
+mod js {
+    use wasm_bindgen::prelude::*;
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = window)]
+        pub fn speak_text(text: &str, lang: &str);
+        #[wasm_bindgen(js_namespace = window)]
+        pub fn can_listen() -> bool;
+        #[wasm_bindgen(js_namespace = window)]
+        pub fn listen_speech(lang: &str, callback: JsValue);
+    }
+}
+
+/// Read `text` aloud in the language `lang` (`es-ES`, `en-US`)
+#[allow(unused_variables)]
+pub fn speak(text: &str, lang: &str) {
+    #[cfg(target_arch = "wasm32")]
+    js::speak_text(text, lang);
+}
+
+/// Whether the browser can recognise speech
+pub fn can_listen() -> bool {
+    #[cfg(target_arch = "wasm32")]
+    {
+        js::can_listen()
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        false
+    }
+}
+
+/// Listen for one utterance in `lang`, then call `on_result` with what was
+/// heard, or `None` if nothing was recognised
+#[allow(unused_variables)]
+pub fn listen(lang: &str, on_result: impl FnOnce(Option<String>) + 'static) {
+    #[cfg(target_arch = "wasm32")]
+    {
+        use wasm_bindgen::prelude::*;
+        js::listen_speech(lang, Closure::once_into_js(on_result));
+    }
+}
@@ -0,0 +1,166 @@
+//! Learning statistics computed from the study event log.
+
+use crate::core::events::StudyEvent;
+use crate::data::stage_for_card_id;
+use std::collections::{BTreeMap, BTreeSet};
+
+/// Distinct cards studied on each day from `first` to `last`, inclusive
+pub fn cards_per_day(events: &[StudyEvent], first: i64, last: i64) -> Vec<usize> {
+    let mut cards: BTreeMap<i64, BTreeSet<u32>> = BTreeMap::new();
+    for event in events {
+        cards.entry(event.day).or_default().insert(event.card_id);
+    }
+    (first..=last)
+        .map(|day| cards.get(&day).map_or(0, BTreeSet::len))
+        .collect()
+}
+
+/// Days in a row with some study, ending today. A streak ending yesterday
+/// still counts, since there is time left to study today.
+pub fn streak(events: &[StudyEvent], today: i64) -> u32 {
+    let days: BTreeSet<i64> = events.iter().map(|event| event.day).collect();
+    let mut day = if days.contains(&today) {
+        today
+    } else {
+        today - 1
+    };
+    let mut streak = 0;
+    while days.contains(&day) {
+        streak += 1;
+        day -= 1;
+    }
+    streak
+}
+
+/// Cards learned by the end of each day from `first` to `last`, inclusive.
+/// A card is learned once it is answered right or graded as known.
+pub fn learned_by_day(events: &[StudyEvent], first: i64, last: i64) -> Vec<usize> {
+    let mut learned_on: BTreeMap<u32, i64> = BTreeMap::new();
+    for event in events {
+        if event.kind.outcome() == Some(true) {
+            learned_on
+                .entry(event.card_id)
+                .and_modify(|day| *day = (*day).min(event.day))
+                .or_insert(event.day);
+        }
+    }
+    (first..=last)
+        .map(|day| {
+            learned_on
+                .values()
+                .filter(|learned| **learned <= day)
+                .count()
+        })
+        .collect()
+}
+
+/// Answers and grades given for the cards of one stage
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StageAccuracy {
+    pub stage: u32,
+    pub correct: usize,
+    pub total: usize,
+}
+
+impl StageAccuracy {
+    pub fn percent(&self) -> usize {
+        self.correct * 100 / self.total.max(1)
+    }
+}
+
+/// Accuracy of answers and grades in each stage that has any, by stage
+pub fn accuracy_by_stage(events: &[StudyEvent]) -> Vec<StageAccuracy> {
+    let mut stages: BTreeMap<u32, StageAccuracy> = BTreeMap::new();
+    for event in events {
+        if let (Some(correct), Some(stage)) =
+            (event.kind.outcome(), stage_for_card_id(event.card_id))
+        {
+            let accuracy = stages.entry(stage).or_insert(StageAccuracy {
+                stage,
+                correct: 0,
+                total: 0,
+            });
+            accuracy.total += 1;
+            if correct {
+                accuracy.correct += 1;
+            }
+        }
+    }
+    stages.into_values().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::events::EventKind;
+
+    fn event(day: i64, card_id: u32, kind: EventKind) -> StudyEvent {
+        StudyEvent {
+            at: day as f64 * 86_400_000.0,
+            day,
+            card_id,
+            kind,
+        }
+    }
+
+    #[test]
+    fn cards_are_counted_once_a_day() {
+        let events = [
+            event(10, 1, EventKind::View),
+            event(10, 1, EventKind::Reveal),
+            event(10, 2, EventKind::View),
+            event(12, 1, EventKind::View),
+        ];
+        assert_eq!(cards_per_day(&events, 9, 12), [0, 2, 0, 1]);
+    }
+
+    #[test]
+    fn streaks_survive_until_the_day_ends() {
+        let events = [
+            event(7, 1, EventKind::View),
+            event(8, 1, EventKind::View),
+            event(9, 1, EventKind::View),
+        ];
+        assert_eq!(streak(&events, 9), 3);
+        assert_eq!(streak(&events, 10), 3);
+        assert_eq!(streak(&events, 11), 0);
+    }
+
+    #[test]
+    fn cards_are_learned_on_their_first_success() {
+        let events = [
+            event(1, 5, EventKind::Grade { known: false }),
+            event(2, 5, EventKind::Grade { known: true }),
+            event(2, 6, EventKind::Answer { correct: true }),
+            event(3, 5, EventKind::Answer { correct: false }),
+        ];
+        assert_eq!(learned_by_day(&events, 1, 3), [0, 2, 2]);
+    }
+
+    #[test]
+    fn accuracy_is_grouped_by_stage() {
+        let events = [
+            event(1, 1, EventKind::Answer { correct: true }),
+            event(1, 2, EventKind::Grade { known: false }),
+            event(1, 21, EventKind::Grade { known: true }),
+            event(1, 21, EventKind::View),
+        ];
+        let stages = accuracy_by_stage(&events);
+        assert_eq!(
+            stages,
+            [
+                StageAccuracy {
+                    stage: 1,
+                    correct: 1,
+                    total: 2
+                },
+                StageAccuracy {
+                    stage: 2,
+                    correct: 1,
+                    total: 1
+                },
+            ]
+        );
+        assert_eq!(stages[0].percent(), 50);
+    }
+}
@@ -0,0 +1,38 @@
+//! Key/value persistence in the browser's `localStorage`.
+//!
+//! Goes through the `storage_get`/`storage_set` helpers in `index.html`,
+//! which swallow errors from private browsing or full quotas. Off the web
+//! nothing is stored.
+
+#[cfg(target_arch = "wasm32")]
+mod js {
+    use wasm_bindgen::prelude::*;
+
+    #[wasm_bindgen]
+    extern "C" {
+        #[wasm_bindgen(js_namespace = window)]
+        pub fn storage_get(key: &str) -> Option<String>;
+        #[wasm_bindgen(js_namespace = window)]
+        pub fn storage_set(key: &str, value: &str);
+    }
+}
+
+/// The value saved under `key`, if any
+#[allow(unused_variables)]
+pub fn load(key: &str) -> Option<String> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        js::storage_get(key)
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        None
+    }
+}
+
+/// Save `value` under `key`, replacing the previous value
+#[allow(unused_variables)]
+pub fn save(key: &str, value: &str) {
+    #[cfg(target_arch = "wasm32")]
+    js::storage_set(key, value);
+}
@@ -0,0 +1,261 @@
+//! Spanish syllabification and stress.
+//!
+//! Words are split into consonant and vowel units (`ch`, `ll`, `rr` and the
+//! silent `u` of `que`/`gui` stay together), vowels are grouped into nuclei
+//! (diphthongs unless two strong vowels meet or a weak vowel carries an
+//! accent), and the consonants between nuclei are shared out following the
+//! usual rules: one goes to the next syllable, `pr`/`bl`-style clusters are
+//! never split, and otherwise only the last consonant moves on.
+
+use std::fmt;
+
+/// Where the stress falls, counted from the end of the word
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stress {
+    /// Last syllable (`ca·fé`, `re·loj`)
+    Aguda,
+    /// Second to last (`ca·sa`, `ár·bol`)
+    Llana,
+    /// Third to last (`mú·si·ca`)
+    Esdrujula,
+    /// Earlier than the third to last (`dí·ga·me·lo`)
+    Sobresdrujula,
+}
+
+impl Stress {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Stress::Aguda => "aguda",
+            Stress::Llana => "llana",
+            Stress::Esdrujula => "esdrújula",
+            Stress::Sobresdrujula => "sobresdrújula",
+        }
+    }
+}
+
+/// A word split into syllables, with the stressed one marked
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Syllables {
+    pub syllables: Vec<String>,
+    /// Index into `syllables` of the stressed syllable
+    pub stressed: usize,
+}
+
+impl Syllables {
+    pub fn stress(&self) -> Stress {
+        match self.syllables.len() - 1 - self.stressed {
+            0 => Stress::Aguda,
+            1 => Stress::Llana,
+            2 => Stress::Esdrujula,
+            _ => Stress::Sobresdrujula,
+        }
+    }
+}
+
+/// Syllables joined with `·`, the stressed one in capitals (`CA·sa`)
+impl fmt::Display for Syllables {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let marked = self.syllables.len() > 1;
+        for (i, syllable) in self.syllables.iter().enumerate() {
+            if i > 0 {
+                write!(f, "·")?;
+            }
+            if marked && i == self.stressed {
+                write!(f, "{}", syllable.to_uppercase())?;
+            } else {
+                write!(f, "{}", syllable)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Split a single Spanish word into syllables; `None` if it has no vowel
+pub fn syllabify(word: &str) -> Option<Syllables> {
+    let chars: Vec<char> = word.chars().collect();
+    let lower: Vec<char> = chars
+        .iter()
+        .map(|c| c.to_lowercase().next().unwrap_or(*c))
+        .collect();
+    let units = units(&lower);
+
+    // Each nucleus starts at a vowel unit that can't join the previous vowel
+    let nuclei: Vec<usize> = (0..units.len())
+        .filter(|&i| {
+            units[i].is_vowel
+                && (i == 0
+                    || !units[i - 1].is_vowel
+                    || (is_strong(lower[units[i - 1].start]) && is_strong(lower[units[i].start])))
+        })
+        .collect();
+    if nuclei.is_empty() {
+        return None;
+    }
+
+    // Syllables start at the first unit, then between each pair of nuclei
+    let mut starts = vec![0];
+    for pair in nuclei.windows(2) {
+        let (vowel_end, next) = (last_vowel(&units, pair[0], pair[1]), pair[1]);
+        let consonants = &units[vowel_end + 1..next];
+        let inseparable = |a: usize| is_cluster(&lower, &consonants[a], &consonants[a + 1]);
+        let boundary = match consonants.len() {
+            0 => next,
+            1 => next - 1,
+            n if inseparable(n - 2) => next - 2,
+            _ => next - 1,
+        };
+        starts.push(boundary);
+    }
+
+    let syllables: Vec<String> = starts
+        .iter()
+        .enumerate()
+        .map(|(i, &start)| {
+            let from = units[start].start;
+            let to = starts
+                .get(i + 1)
+                .map(|&next| units[next].start)
+                .unwrap_or(chars.len());
+            chars[from..to].iter().collect()
+        })
+        .collect();
+
+    let stressed = syllables
+        .iter()
+        .position(|s| s.to_lowercase().chars().any(has_written_accent))
+        .unwrap_or_else(|| {
+            let ends_llana = matches!(lower.last(), Some('a' | 'e' | 'i' | 'o' | 'u' | 'n' | 's'));
+            if ends_llana && syllables.len() > 1 {
+                syllables.len() - 2
+            } else {
+                syllables.len() - 1
+            }
+        });
+
+    Some(Syllables {
+        syllables,
+        stressed,
+    })
+}
+
+/// A run of characters acting as one vowel or one consonant
+struct Unit {
+    start: usize,
+    len: usize,
+    is_vowel: bool,
+}
+
+fn units(lower: &[char]) -> Vec<Unit> {
+    let mut units = Vec::new();
+    let mut i = 0;
+    while i < lower.len() {
+        let c = lower[i];
+        let next = lower.get(i + 1).copied();
+        let after = lower.get(i + 2).copied();
+        let len = match (c, next) {
+            ('c', Some('h')) | ('l', Some('l')) | ('r', Some('r')) => 2,
+            // The u of que/qui/gue/gui is silent and belongs to the consonant
+            ('q' | 'g', Some('u')) if matches!(after, Some('e' | 'i' | 'é' | 'í')) => 2,
+            _ => 1,
+        };
+        let is_vowel = match c {
+            // Final y after a vowel sounds like i (hoy, muy)
+            'y' => i + 1 == lower.len() && i > 0 && is_vowel(lower[i - 1]),
+            _ => len == 1 && is_vowel(c),
+        };
+        units.push(Unit {
+            start: i,
+            len,
+            is_vowel,
+        });
+        i += len;
+    }
+    units
+}
+
+/// Index of the last vowel unit of the nucleus starting at `start`, which
+/// ends before the next nucleus at `next`
+fn last_vowel(units: &[Unit], start: usize, next: usize) -> usize {
+    let mut end = start;
+    while end + 1 < next && units[end + 1].is_vowel {
+        end += 1;
+    }
+    end
+}
+
+fn is_vowel(c: char) -> bool {
+    "aeiouáéíóúü".contains(c)
+}
+
+/// Strong vowels never form a diphthong together; an accented i or u acts
+/// as a strong vowel (`dí·a`, `pa·ís`)
+fn is_strong(c: char) -> bool {
+    "aeoáéíóú".contains(c)
+}
+
+fn has_written_accent(c: char) -> bool {
+    "áéíóú".contains(c)
+}
+
+/// Consonant pairs that always start a syllable together (`pr`, `bl`, `tr`)
+fn is_cluster(lower: &[char], first: &Unit, second: &Unit) -> bool {
+    if first.len != 1 || second.len != 1 {
+        return false;
+    }
+    matches!(
+        (lower[first.start], lower[second.start]),
+        ('p' | 'b' | 'c' | 'g' | 'f' | 'k', 'l' | 'r') | ('t' | 'd', 'r')
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn split(word: &str) -> String {
+        syllabify(word).map(|s| s.to_string()).unwrap_or_default()
+    }
+
+    #[test]
+    fn stress_follows_the_word_ending() {
+        assert_eq!(split("casa"), "CA·sa");
+        assert_eq!(split("examen"), "e·XA·men");
+        assert_eq!(split("reloj"), "re·LOJ");
+        assert_eq!(split("estoy"), "es·TOY");
+    }
+
+    #[test]
+    fn written_accents_mark_the_stress() {
+        assert_eq!(split("árbol"), "ÁR·bol");
+        assert_eq!(split("café"), "ca·FÉ");
+        assert_eq!(split("música"), "MÚ·si·ca");
+        assert_eq!(syllabify("música").unwrap().stress(), Stress::Esdrujula);
+    }
+
+    #[test]
+    fn diphthongs_and_hiatus() {
+        assert_eq!(split("ciudad"), "ciu·DAD");
+        assert_eq!(split("tiempo"), "TIEM·po");
+        assert_eq!(split("día"), "DÍ·a");
+        assert_eq!(split("país"), "pa·ÍS");
+        assert_eq!(split("aeropuerto"), "a·e·ro·PUER·to");
+    }
+
+    #[test]
+    fn consonant_clusters() {
+        assert_eq!(split("perro"), "PE·rro");
+        assert_eq!(split("calle"), "CA·lle");
+        assert_eq!(split("noche"), "NO·che");
+        assert_eq!(split("hablar"), "ha·BLAR");
+        assert_eq!(split("instante"), "ins·TAN·te");
+        assert_eq!(split("queso"), "QUE·so");
+        assert_eq!(split("siempre"), "SIEM·pre");
+    }
+
+    #[test]
+    fn monosyllables_are_not_marked() {
+        assert_eq!(split("sol"), "sol");
+        assert_eq!(split("hoy"), "hoy");
+        assert_eq!(syllabify("y"), None);
+    }
+}
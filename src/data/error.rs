@@ -0,0 +1,136 @@
+use std::fmt;
+
+/// Errors raised while loading vocabulary and grammar content
+#[derive(Debug, Clone, PartialEq)]
+pub enum DataError {
+    /// No content exists for this stage number
+    UnknownStage { stage: u32 },
+    /// No translations exist for this language code
+    UnknownLanguage { language: String },
+    /// A bundled JSON file is malformed
+    Parse {
+        stage: u32,
+        file: String,
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// A card index beyond the end of the stage
+    IndexOutOfRange {
+        stage: u32,
+        index: usize,
+        len: usize,
+    },
+    /// The Spanish and English files of a stage don't list the same cards
+    MisalignedTranslations {
+        stage: u32,
+        index: usize,
+        spanish_id: Option<u32>,
+        english_id: Option<u32>,
+    },
+    /// No grammar exercises exist for this topic
+    UnknownTopic { topic: String },
+    /// A bundled grammar file is malformed
+    GrammarParse {
+        file: String,
+        line: usize,
+        column: usize,
+        message: String,
+    },
+    /// A grammar exercise can't be answered or explained
+    InvalidExercise {
+        topic: String,
+        id: u32,
+        problem: String,
+    },
+    /// No grammar lesson exists with this name
+    UnknownLesson { lesson: String },
+    /// A grammar lesson has a broken quiz, table or link
+    InvalidLesson { lesson: String, problem: String },
+}
+
+impl DataError {
+    pub(super) fn parse(stage: u32, file: &str, error: serde_json::Error) -> Self {
+        DataError::Parse {
+            stage,
+            file: file.to_string(),
+            line: error.line(),
+            column: error.column(),
+            message: error.to_string(),
+        }
+    }
+
+    pub(super) fn grammar_parse(file: &str, error: serde_json::Error) -> Self {
+        DataError::GrammarParse {
+            file: file.to_string(),
+            line: error.line(),
+            column: error.column(),
+            message: error.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for DataError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DataError::UnknownStage { stage } => write!(f, "Stage {} not found", stage),
+            DataError::UnknownLanguage { language } => {
+                write!(f, "Language {} not found", language)
+            }
+            DataError::Parse {
+                stage,
+                file,
+                line,
+                column,
+                message,
+            } => write!(
+                f,
+                "Failed to parse {} for stage {} at line {}, column {}: {}",
+                file, stage, line, column, message
+            ),
+            DataError::IndexOutOfRange { stage, index, len } => write!(
+                f,
+                "Card {} is out of range, stage {} has {} cards",
+                index + 1,
+                stage,
+                len
+            ),
+            DataError::MisalignedTranslations {
+                stage,
+                index,
+                spanish_id,
+                english_id,
+            } => {
+                let id = |id: &Option<u32>| id.map_or("missing".to_string(), |id| id.to_string());
+                write!(
+                    f,
+                    "Translations of stage {} don't match at card {}: Spanish ID {}, English ID {}",
+                    stage,
+                    index + 1,
+                    id(spanish_id),
+                    id(english_id)
+                )
+            }
+            DataError::UnknownTopic { topic } => write!(f, "Grammar topic {} not found", topic),
+            DataError::GrammarParse {
+                file,
+                line,
+                column,
+                message,
+            } => write!(
+                f,
+                "Failed to parse grammar/{} at line {}, column {}: {}",
+                file, line, column, message
+            ),
+            DataError::InvalidExercise { topic, id, problem } => {
+                write!(f, "Exercise {} of {} is invalid: {}", id, topic, problem)
+            }
+            DataError::UnknownLesson { lesson } => write!(f, "Grammar lesson {} not found", lesson),
+            DataError::InvalidLesson { lesson, problem } => {
+                write!(f, "Lesson {} is invalid: {}", lesson, problem)
+            }
+        }
+    }
+}
+
+impl std::error::Error for DataError {}
@@ -0,0 +1,255 @@
+use super::{CefrLevel, DataError, LocalizedText};
+use serde::{Deserialize, Serialize};
+
+/// Marks the gap in an exercise sentence
+pub const GAP: &str = "___";
+
+/// Grammar topics with gap exercises, in the order they are listed
+pub const GRAMMAR_TOPICS: [&str; 2] = ["ser-estar", "por-para"];
+
+/// Grammar lessons, in the order they are listed
+pub const GRAMMAR_LESSONS: [&str; 3] = ["present-tense", "ser-estar", "por-para"];
+
+/// Rule of usage that exercises point back to in their explanations
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct GrammarRule {
+    pub id: String,
+    pub title: LocalizedText,
+    pub explanation: LocalizedText,
+}
+
+/// A sentence with one or more gaps, all filled by the same choice
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct GrammarExercise {
+    pub id: u32,
+    pub sentence: String,
+    pub translation: String,
+    pub choices: Vec<String>,
+    pub answer: String,
+    /// ID of the rule explaining the answer
+    pub rule: String,
+}
+
+impl GrammarExercise {
+    /// The sentence with every gap filled by `word`
+    pub fn filled(&self, word: &str) -> String {
+        self.sentence.replace(GAP, word)
+    }
+}
+
+/// The rules and exercises of a grammar topic
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct GrammarExerciseSet {
+    pub topic: String,
+    pub emoji: String,
+    pub title: LocalizedText,
+    pub description: LocalizedText,
+    pub rules: Vec<GrammarRule>,
+    pub exercises: Vec<GrammarExercise>,
+}
+
+impl GrammarExerciseSet {
+    pub fn rule(&self, id: &str) -> Option<&GrammarRule> {
+        self.rules.iter().find(|rule| rule.id == id)
+    }
+
+    /// The first exercise that can't be answered or explained, and why
+    fn find_invalid(&self) -> Option<(u32, String)> {
+        self.exercises.iter().find_map(|exercise| {
+            let problem = if !exercise.sentence.contains(GAP) {
+                format!("the sentence has no {} gap", GAP)
+            } else if !exercise.choices.contains(&exercise.answer) {
+                format!(
+                    "the answer \"{}\" isn't one of the choices",
+                    exercise.answer
+                )
+            } else if self.rule(&exercise.rule).is_none() {
+                format!("the rule \"{}\" doesn't exist", exercise.rule)
+            } else {
+                return None;
+            };
+            Some((exercise.id, problem))
+        })
+    }
+}
+
+/// Load the gap exercises of a grammar topic
+pub fn load_grammar_exercises(topic: &str) -> Result<GrammarExerciseSet, DataError> {
+    let json_data = match topic {
+        "ser-estar" => include_str!("../../translations/grammar/exercises/ser-estar.json"),
+        "por-para" => include_str!("../../translations/grammar/exercises/por-para.json"),
+        _ => {
+            return Err(DataError::UnknownTopic {
+                topic: topic.to_string(),
+            });
+        }
+    };
+
+    let set: GrammarExerciseSet = serde_json::from_str(json_data)
+        .map_err(|e| DataError::grammar_parse(&format!("exercises/{}.json", topic), e))?;
+    match set.find_invalid() {
+        Some((id, problem)) => Err(DataError::InvalidExercise {
+            topic: topic.to_string(),
+            id,
+            problem,
+        }),
+        None => Ok(set),
+    }
+}
+
+/// A piece of a lesson, rendered in order
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum LessonBlock {
+    Heading {
+        text: String,
+    },
+    Paragraph {
+        text: String,
+    },
+    Table {
+        header: Vec<String>,
+        rows: Vec<Vec<String>>,
+    },
+    /// A Spanish sentence that can be read aloud, with its translation
+    Example {
+        spanish: String,
+        english: String,
+    },
+    /// A multiple choice question answered inline
+    Quiz {
+        question: String,
+        choices: Vec<String>,
+        answer: String,
+        explanation: String,
+    },
+}
+
+/// A grammar lesson document
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct GrammarLesson {
+    pub lesson: String,
+    pub emoji: String,
+    pub cefr: CefrLevel,
+    pub title: LocalizedText,
+    pub summary: LocalizedText,
+    /// Topic of the gap exercises that practice the lesson
+    #[serde(default)]
+    pub practice: Option<String>,
+    pub blocks: Vec<LessonBlock>,
+}
+
+impl GrammarLesson {
+    /// Why the lesson can't be shown as written, if it can't
+    fn find_invalid(&self) -> Option<String> {
+        if let Some(topic) = &self.practice
+            && !GRAMMAR_TOPICS.contains(&topic.as_str())
+        {
+            return Some(format!("the practice topic \"{}\" doesn't exist", topic));
+        }
+        self.blocks.iter().find_map(|block| match block {
+            LessonBlock::Table { header, rows } => rows
+                .iter()
+                .any(|row| row.len() != header.len())
+                .then(|| format!("a table row doesn't have {} cells", header.len())),
+            LessonBlock::Quiz {
+                question,
+                choices,
+                answer,
+                ..
+            } => (!choices.contains(answer))
+                .then(|| format!("the answer to \"{}\" isn't one of the choices", question)),
+            _ => None,
+        })
+    }
+}
+
+/// Load a grammar lesson
+pub fn load_grammar_lesson(lesson: &str) -> Result<GrammarLesson, DataError> {
+    let json_data = match lesson {
+        "present-tense" => include_str!("../../translations/grammar/lessons/present-tense.json"),
+        "ser-estar" => include_str!("../../translations/grammar/lessons/ser-estar.json"),
+        "por-para" => include_str!("../../translations/grammar/lessons/por-para.json"),
+        _ => {
+            return Err(DataError::UnknownLesson {
+                lesson: lesson.to_string(),
+            });
+        }
+    };
+
+    let document: GrammarLesson = serde_json::from_str(json_data)
+        .map_err(|e| DataError::grammar_parse(&format!("lessons/{}.json", lesson), e))?;
+    match document.find_invalid() {
+        Some(problem) => Err(DataError::InvalidLesson {
+            lesson: lesson.to_string(),
+            problem,
+        }),
+        None => Ok(document),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bundled_topics_load() {
+        for topic in GRAMMAR_TOPICS {
+            let set = load_grammar_exercises(topic).unwrap();
+            assert_eq!(set.topic, topic);
+            assert!(!set.exercises.is_empty());
+        }
+    }
+
+    #[test]
+    fn unknown_topics_are_errors() {
+        assert_eq!(
+            load_grammar_exercises("subjuntivo"),
+            Err(DataError::UnknownTopic {
+                topic: "subjuntivo".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn exercises_need_a_valid_answer_and_rule() {
+        let mut set = load_grammar_exercises("por-para").unwrap();
+        set.exercises[0].answer = "en".to_string();
+        assert!(set.find_invalid().is_some_and(|(id, _)| id == 1));
+
+        let mut set = load_grammar_exercises("por-para").unwrap();
+        set.exercises[0].rule = "por-nada".to_string();
+        assert!(set.find_invalid().is_some());
+    }
+
+    #[test]
+    fn bundled_lessons_load() {
+        for lesson in GRAMMAR_LESSONS {
+            let document = load_grammar_lesson(lesson).unwrap();
+            assert_eq!(document.lesson, lesson);
+            assert!(!document.blocks.is_empty());
+        }
+    }
+
+    #[test]
+    fn lesson_quizzes_need_a_valid_answer() {
+        let mut document = load_grammar_lesson("por-para").unwrap();
+        document.blocks.push(LessonBlock::Quiz {
+            question: "Voy ___ casa.".to_string(),
+            choices: vec!["por".to_string(), "para".to_string()],
+            answer: "a".to_string(),
+            explanation: String::new(),
+        });
+        assert!(document.find_invalid().is_some());
+    }
+
+    #[test]
+    fn every_gap_is_filled() {
+        let set = load_grammar_exercises("por-para").unwrap();
+        let exercise = set.exercises.iter().find(|e| e.id == 12).unwrap();
+        assert_eq!(
+            exercise.filled(&exercise.answer),
+            "Trabajo por la mañana y estudio por la tarde."
+        );
+    }
+}
@@ -1,13 +1,32 @@
+mod error;
+mod grammar;
+
+use crate::core::language::Language;
 use serde::{Deserialize, Serialize};
 
+pub use error::DataError;
+pub use grammar::{
+    GAP, GRAMMAR_LESSONS, GRAMMAR_TOPICS, GrammarExercise, LessonBlock, load_grammar_exercises,
+    load_grammar_lesson,
+};
+
 /// Represents a single vocabulary card with translations
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
 pub struct VocabularyCard {
     pub id: u32,
     pub word: String,
     pub example: String,
+    /// Other valid word orders of the example
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub alternatives: Vec<String>,
 }
 
+/// Number of vocabulary stages bundled with the app
+pub const STAGE_COUNT: u32 = 21;
+
+/// Number of cards in every vocabulary stage
+pub const CARDS_PER_STAGE: u32 = 20;
+
 /// Language direction for learning
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum LearningDirection {
@@ -15,8 +34,76 @@ pub enum LearningDirection {
     EnglishToSpanish,
 }
 
+impl LearningDirection {
+    /// Value of the `dir` query param for this direction
+    pub fn as_query(&self) -> &'static str {
+        match self {
+            LearningDirection::SpanishToEnglish => "es-to-en",
+            LearningDirection::EnglishToSpanish => "en-to-es",
+        }
+    }
+
+    /// Language of the side shown first
+    pub fn source_language(&self) -> Language {
+        match self {
+            LearningDirection::SpanishToEnglish => Language::Spanish,
+            LearningDirection::EnglishToSpanish => Language::English,
+        }
+    }
+
+    /// Language of the side being recalled
+    pub fn target_language(&self) -> Language {
+        match self {
+            LearningDirection::SpanishToEnglish => Language::English,
+            LearningDirection::EnglishToSpanish => Language::Spanish,
+        }
+    }
+}
+
+/// Directions a study session presents cards in
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DirectionMode {
+    Single(LearningDirection),
+    /// Each card in either direction
+    Mixed,
+}
+
+impl DirectionMode {
+    /// Parse the `dir` query param, defaulting to Spanish → English
+    pub fn from_query(dir: Option<&str>) -> Self {
+        match dir {
+            Some("en-to-es") => DirectionMode::Single(LearningDirection::EnglishToSpanish),
+            Some("both") => DirectionMode::Mixed,
+            _ => DirectionMode::Single(LearningDirection::SpanishToEnglish),
+        }
+    }
+
+    /// Direction of pages that show a whole session one way; mixed sessions
+    /// take Spanish → English there
+    pub fn primary(&self) -> LearningDirection {
+        match self {
+            DirectionMode::Single(direction) => *direction,
+            DirectionMode::Mixed => LearningDirection::SpanishToEnglish,
+        }
+    }
+
+    /// Value of the `dir` query param for this mode
+    pub fn as_query(&self) -> &'static str {
+        match self {
+            DirectionMode::Single(direction) => direction.as_query(),
+            DirectionMode::Mixed => "both",
+        }
+    }
+}
+
 /// Load vocabulary cards for a specific stage and language
-pub fn load_vocabulary_stage(stage: u32, language: &str) -> Result<Vec<VocabularyCard>, String> {
+pub fn load_vocabulary_stage(stage: u32, language: &str) -> Result<Vec<VocabularyCard>, DataError> {
+    if language != "es" && language != "en" {
+        return Err(DataError::UnknownLanguage {
+            language: language.to_string(),
+        });
+    }
+
     let json_data = match (stage, language) {
         (1, "es") => include_str!("../../translations/vocabulary/1/es.json"),
         (1, "en") => include_str!("../../translations/vocabulary/1/en.json"),
@@ -60,16 +147,20 @@ pub fn load_vocabulary_stage(stage: u32, language: &str) -> Result<Vec<Vocabular
         (20, "en") => include_str!("../../translations/vocabulary/20/en.json"),
         (21, "es") => include_str!("../../translations/vocabulary/21/es.json"),
         (21, "en") => include_str!("../../translations/vocabulary/21/en.json"),
-        _ => {
-            return Err(format!(
-                "Stage {} for language {} not found",
-                stage, language
-            ));
-        }
+        _ => return Err(DataError::UnknownStage { stage }),
     };
 
-    serde_json::from_str(json_data)
-        .map_err(|e| format!("Failed to parse JSON for stage {}: {}", stage, e))
+    parse_vocabulary(stage, language, json_data)
+}
+
+/// Parse the cards of one language file of a stage
+fn parse_vocabulary(
+    stage: u32,
+    language: &str,
+    json: &str,
+) -> Result<Vec<VocabularyCard>, DataError> {
+    serde_json::from_str(json)
+        .map_err(|e| DataError::parse(stage, &format!("{}.json", language), e))
 }
 
 /// Get a pair of cards (source and target language) for a specific stage and card index
@@ -77,12 +168,29 @@ pub fn get_card_pair(
     stage: u32,
     card_index: usize,
     direction: LearningDirection,
-) -> Result<(VocabularyCard, VocabularyCard), String> {
+) -> Result<(VocabularyCard, VocabularyCard), DataError> {
     let spanish_cards = load_vocabulary_stage(stage, "es")?;
     let english_cards = load_vocabulary_stage(stage, "en")?;
 
-    if card_index >= spanish_cards.len() || card_index >= english_cards.len() {
-        return Err("Card index out of bounds".to_string());
+    let spanish = spanish_cards.get(card_index);
+    let english = english_cards.get(card_index);
+    match (spanish, english) {
+        (None, None) => {
+            return Err(DataError::IndexOutOfRange {
+                stage,
+                index: card_index,
+                len: spanish_cards.len().min(english_cards.len()),
+            });
+        }
+        (Some(s), Some(e)) if s.id == e.id => {}
+        _ => {
+            return Err(DataError::MisalignedTranslations {
+                stage,
+                index: card_index,
+                spanish_id: spanish.map(|c| c.id),
+                english_id: english.map(|c| c.id),
+            });
+        }
     }
 
     match direction {
@@ -97,8 +205,203 @@ pub fn get_card_pair(
     }
 }
 
+/// Text available in every UI language
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct LocalizedText {
+    pub en: String,
+    pub es: String,
+}
+
+impl LocalizedText {
+    /// Text for the given language
+    pub fn get(&self, language: Language) -> &str {
+        match language {
+            Language::English => &self.en,
+            Language::Spanish => &self.es,
+        }
+    }
+}
+
+/// Common European Framework of Reference level
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord)]
+pub enum CefrLevel {
+    A1,
+    A2,
+    B1,
+    B2,
+    C1,
+    C2,
+}
+
+impl CefrLevel {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CefrLevel::A1 => "A1",
+            CefrLevel::A2 => "A2",
+            CefrLevel::B1 => "B1",
+            CefrLevel::B2 => "B2",
+            CefrLevel::C1 => "C1",
+            CefrLevel::C2 => "C2",
+        }
+    }
+}
+
+/// Descriptive metadata for a vocabulary stage
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct StageMeta {
+    pub stage: u32,
+    pub emoji: String,
+    pub cefr: CefrLevel,
+    /// Stages worth finishing before this one
+    pub prerequisites: Vec<u32>,
+    pub title: LocalizedText,
+    pub description: LocalizedText,
+}
+
+/// Load the metadata (title, theme, level) of a stage
+pub fn load_stage_meta(stage: u32) -> Result<StageMeta, DataError> {
+    let json_data = match stage {
+        1 => include_str!("../../translations/vocabulary/1/meta.json"),
+        2 => include_str!("../../translations/vocabulary/2/meta.json"),
+        3 => include_str!("../../translations/vocabulary/3/meta.json"),
+        4 => include_str!("../../translations/vocabulary/4/meta.json"),
+        5 => include_str!("../../translations/vocabulary/5/meta.json"),
+        6 => include_str!("../../translations/vocabulary/6/meta.json"),
+        7 => include_str!("../../translations/vocabulary/7/meta.json"),
+        8 => include_str!("../../translations/vocabulary/8/meta.json"),
+        9 => include_str!("../../translations/vocabulary/9/meta.json"),
+        10 => include_str!("../../translations/vocabulary/10/meta.json"),
+        11 => include_str!("../../translations/vocabulary/11/meta.json"),
+        12 => include_str!("../../translations/vocabulary/12/meta.json"),
+        13 => include_str!("../../translations/vocabulary/13/meta.json"),
+        14 => include_str!("../../translations/vocabulary/14/meta.json"),
+        15 => include_str!("../../translations/vocabulary/15/meta.json"),
+        16 => include_str!("../../translations/vocabulary/16/meta.json"),
+        17 => include_str!("../../translations/vocabulary/17/meta.json"),
+        18 => include_str!("../../translations/vocabulary/18/meta.json"),
+        19 => include_str!("../../translations/vocabulary/19/meta.json"),
+        20 => include_str!("../../translations/vocabulary/20/meta.json"),
+        21 => include_str!("../../translations/vocabulary/21/meta.json"),
+        _ => return Err(DataError::UnknownStage { stage }),
+    };
+
+    serde_json::from_str(json_data).map_err(|e| DataError::parse(stage, "meta.json", e))
+}
+
+/// A card in both languages, as listed in the catalogue
+#[derive(Debug, Clone, PartialEq)]
+pub struct CatalogueEntry {
+    pub stage: u32,
+    pub spanish: VocabularyCard,
+    pub english: VocabularyCard,
+}
+
+/// Load every card of every stage in both languages
+pub fn load_catalogue() -> Result<Vec<CatalogueEntry>, DataError> {
+    let mut entries = Vec::new();
+    for stage in 1..=STAGE_COUNT {
+        let spanish_cards = load_vocabulary_stage(stage, "es")?;
+        let english_cards = load_vocabulary_stage(stage, "en")?;
+        if let Some(index) = (0..spanish_cards.len().max(english_cards.len()))
+            .find(|i| spanish_cards.get(*i).map(|c| c.id) != english_cards.get(*i).map(|c| c.id))
+        {
+            return Err(DataError::MisalignedTranslations {
+                stage,
+                index,
+                spanish_id: spanish_cards.get(index).map(|c| c.id),
+                english_id: english_cards.get(index).map(|c| c.id),
+            });
+        }
+        entries.extend(
+            spanish_cards
+                .into_iter()
+                .zip(english_cards)
+                .map(|(spanish, english)| CatalogueEntry {
+                    stage,
+                    spanish,
+                    english,
+                }),
+        );
+    }
+    Ok(entries)
+}
+
 /// Get total number of cards in a stage
-pub fn get_stage_card_count(stage: u32) -> Result<usize, String> {
+pub fn get_stage_card_count(stage: u32) -> Result<usize, DataError> {
     let cards = load_vocabulary_stage(stage, "es")?;
     Ok(cards.len())
 }
+
+/// Get the stage a global card ID belongs to
+pub fn stage_for_card_id(card_id: u32) -> Option<u32> {
+    if card_id == 0 || card_id > STAGE_COUNT * CARDS_PER_STAGE {
+        return None;
+    }
+    Some((card_id - 1) / CARDS_PER_STAGE + 1)
+}
+
+/// Convert a global card ID into its stage-relative card index
+pub fn card_index_for_id(card_id: u32) -> Option<usize> {
+    stage_for_card_id(card_id).map(|stage| (card_id - ((stage - 1) * CARDS_PER_STAGE + 1)) as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn unknown_stages_and_languages_are_reported() {
+        for stage in [0, STAGE_COUNT + 1] {
+            assert_eq!(
+                load_vocabulary_stage(stage, "es"),
+                Err(DataError::UnknownStage { stage })
+            );
+        }
+        // The language is checked first
+        assert_eq!(
+            load_vocabulary_stage(0, "fr"),
+            Err(DataError::UnknownLanguage {
+                language: "fr".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn malformed_files_report_where() {
+        let json = "[\n  {\"id\": 1, \"word\": \"casa\",}\n]";
+        match parse_vocabulary(4, "es", json) {
+            Err(DataError::Parse {
+                stage,
+                file,
+                line,
+                column,
+                ..
+            }) => {
+                assert_eq!((stage, file.as_str()), (4, "es.json"));
+                // The closing brace after the trailing comma
+                assert_eq!((line, column), (2, 28));
+            }
+            other => panic!("expected a parse error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn stage_metadata_is_consistent() {
+        for stage in 1..=STAGE_COUNT {
+            let meta = load_stage_meta(stage).unwrap();
+            assert_eq!(meta.stage, stage, "meta.json of stage {}", stage);
+            assert!(
+                meta.prerequisites.iter().all(|p| (1..stage).contains(p)),
+                "stage {} depends on a later stage: {:?}",
+                stage,
+                meta.prerequisites
+            );
+        }
+        assert_eq!(
+            load_stage_meta(STAGE_COUNT + 1),
+            Err(DataError::UnknownStage {
+                stage: STAGE_COUNT + 1
+            })
+        );
+    }
+}
@@ -9,8 +9,17 @@ mod core;
 mod data;
 mod pages;
 
-use core::FavoritesContext;
-use pages::{Favorites, Grammar, Home, Vocabulary, VocabularyCards};
+use components::{KeymapOverlay, UpdateBanner};
+use core::{
+    EventLogContext, FavoritesContext, KeymapContext, MasteryContext, MistakesContext,
+    ProgressContext, SettingsContext,
+};
+use pages::{
+    AccentPractice, AgreementPractice, ClozePractice, DictationPractice, Favorites,
+    FavoritesImport, GenderPractice, Grammar, GrammarExercises, Home, Lesson, LessonIndex,
+    Mistakes, NumbersPractice, PlacementTest, ScramblePractice, Search, Settings, Stats,
+    Vocabulary, VocabularyCards,
+};
 
 fn main() {
     leptos::mount::mount_to_body(|| view! { <App/> })
@@ -19,15 +28,41 @@ fn main() {
 #[component]
 fn App() -> impl IntoView {
     provide_context(FavoritesContext::new());
+    provide_context(KeymapContext::new());
+    provide_context(SettingsContext::new());
+    provide_context(ProgressContext::new());
+    provide_context(MasteryContext::new());
+    provide_context(EventLogContext::new());
+    provide_context(MistakesContext::new());
+    core::events::use_event_log_flush();
+    core::reminders::use_goal_reminders();
 
     view! {
+        <UpdateBanner/>
+        <KeymapOverlay/>
         <Router>
             <Routes fallback=|| "Page not found">
                 <Route path=path!("/") view=Home/>
                 <Route path=path!("/vocabulary") view=Vocabulary/>
                 <Route path=path!("/vocabulary/favorites") view=Favorites/>
+                <Route path=path!("/vocabulary/favorites/import") view=FavoritesImport/>
+                <Route path=path!("/vocabulary/placement") view=PlacementTest/>
+                <Route path=path!("/vocabulary/mistakes") view=Mistakes/>
                 <Route path=path!("/vocabulary/:stage") view=VocabularyCards/>
+                <Route path=path!("/vocabulary/:stage/cloze") view=ClozePractice/>
+                <Route path=path!("/vocabulary/:stage/dictation") view=DictationPractice/>
+                <Route path=path!("/vocabulary/:stage/scramble") view=ScramblePractice/>
                 <Route path=path!("/grammar") view=Grammar/>
+                <Route path=path!("/grammar/accents") view=AccentPractice/>
+                <Route path=path!("/grammar/gender") view=GenderPractice/>
+                <Route path=path!("/grammar/agreement") view=AgreementPractice/>
+                <Route path=path!("/grammar/numbers") view=NumbersPractice/>
+                <Route path=path!("/grammar/exercises/:topic") view=GrammarExercises/>
+                <Route path=path!("/grammar/lessons") view=LessonIndex/>
+                <Route path=path!("/grammar/lessons/:lesson") view=Lesson/>
+                <Route path=path!("/search") view=Search/>
+                <Route path=path!("/settings") view=Settings/>
+                <Route path=path!("/stats") view=Stats/>
             </Routes>
         </Router>
     }
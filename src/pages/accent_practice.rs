@@ -0,0 +1,189 @@
+use crate::components::DataErrorMessage;
+use crate::core::accent::{AccentRule, Accentuation, accentuation, strip_tildes};
+use crate::core::headword::is_article;
+use crate::core::keymap::{KeyBinding, use_key_bindings};
+use crate::core::text::shuffle_key;
+use crate::data::{DataError, LearningDirection, STAGE_COUNT, load_vocabulary_stage};
+use leptos::prelude::*;
+use leptos_router::components::A;
+
+/// Number of words asked in one round
+const ROUND_SIZE: usize = 10;
+
+/// Distinct Spanish headwords worth asking about, in a fixed shuffled order.
+/// Diacritic pairs are left out since the bare word can't tell them apart.
+fn practice_words() -> Result<Vec<Accentuation>, DataError> {
+    let mut words: Vec<String> = Vec::new();
+    for stage in 1..=STAGE_COUNT {
+        for card in load_vocabulary_stage(stage, "es")? {
+            for word in card.word.split(|c: char| !c.is_alphabetic()) {
+                let lower = word.to_lowercase();
+                if lower.chars().count() > 2 && !is_article(&lower) && !words.contains(&lower) {
+                    words.push(lower);
+                }
+            }
+        }
+    }
+    let mut words: Vec<Accentuation> = words
+        .iter()
+        .filter_map(|w| accentuation(w))
+        .filter(|a| a.syllables.syllables.len() > 1)
+        .filter(|a| !matches!(a.rule, AccentRule::Diacritic(..)))
+        .collect();
+    // Mix the stages so a round isn't all from the same topic
+    words.sort_by_key(|a| shuffle_key(&a.syllables.syllables.concat()));
+    Ok(words)
+}
+
+/// Written accent practice - Decide whether and where a word takes a tilde
+#[component]
+pub fn AccentPractice() -> impl IntoView {
+    let words = Memo::new(move |_| practice_words());
+
+    // State management
+    let (round, set_round) = signal(0usize);
+    let (index, set_index) = signal(0usize);
+    // The learner's answer: the syllable picked for the tilde, or `None` for no tilde
+    let (answer, set_answer) = signal(None::<Option<usize>>);
+    let (score, set_score) = signal(0usize);
+
+    let current = move || {
+        words.read().as_ref().ok().and_then(|all| {
+            (index.get() < ROUND_SIZE && !all.is_empty())
+                .then(|| all[(round.get() * ROUND_SIZE + index.get()) % all.len()].clone())
+        })
+    };
+
+    let pick = move |choice: Option<usize>| {
+        if answer.get_untracked().is_some() {
+            return;
+        }
+        if let Some(word) = current() {
+            if choice.is_some_and(|c| c >= word.syllables.syllables.len()) {
+                return;
+            }
+            if word.tilde == choice {
+                set_score.update(|s| *s += 1);
+            }
+            set_answer.set(Some(choice));
+        }
+    };
+
+    let go_next = move || {
+        if answer.get_untracked().is_some() {
+            set_index.update(|i| *i += 1);
+            set_answer.set(None);
+        }
+    };
+
+    let next_round = move |_| {
+        set_round.update(|r| *r += 1);
+        set_index.set(0);
+        set_score.set(0);
+        set_answer.set(None);
+    };
+
+    use_key_bindings(vec![
+        KeyBinding::new(&["0"], "0", "No tilde", move || pick(None)),
+        KeyBinding::new(&["1"], "1", "Tilde on the first syllable", move || {
+            pick(Some(0))
+        }),
+        KeyBinding::new(&["2"], "2", "Tilde on the second syllable", move || {
+            pick(Some(1))
+        }),
+        KeyBinding::new(&["3"], "3", "Tilde on the third syllable", move || {
+            pick(Some(2))
+        }),
+        KeyBinding::new(&["4"], "4", "Tilde on the fourth syllable", move || {
+            pick(Some(3))
+        }),
+        KeyBinding::new(&["5"], "5", "Tilde on the fifth syllable", move || {
+            pick(Some(4))
+        }),
+        KeyBinding::new(&["ArrowRight"], "→", "Next word", go_next),
+    ]);
+
+    view! {
+        <div class="page-container">
+            <header class="page-header">
+                <A href="/grammar" attr:class="back-button">"❮"</A>
+                <h1>"Written accents"</h1>
+            </header>
+
+            <div class="cloze-container">
+                {move || match words.get() {
+                    Err(e) => view! {
+                        <DataErrorMessage error=e direction=LearningDirection::SpanishToEnglish/>
+                    }.into_any(),
+                    Ok(_) => match current() {
+                        Some(word) => {
+                            let choice_class = move |choice: Option<usize>| {
+                                let tilde = word.tilde;
+                                move || match answer.get() {
+                                    Some(_) if choice == tilde => "cloze-choice correct",
+                                    Some(picked) if picked == choice => "cloze-choice incorrect",
+                                    _ => "cloze-choice",
+                                }
+                            };
+                            let syllables = word.syllables.syllables.iter().enumerate().map(|(i, syllable)| {
+                                let label = strip_tildes(syllable);
+                                view! {
+                                    {(i > 0).then_some(view! { <span class="accent-dot">"·"</span> })}
+                                    <button
+                                        class=choice_class(Some(i))
+                                        disabled=move || answer.get().is_some()
+                                        on:click=move |_| pick(Some(i))
+                                    >
+                                        <kbd>{(i + 1).to_string()}</kbd>
+                                        {label}
+                                    </button>
+                                }
+                            }).collect::<Vec<_>>();
+                            let spelled = word.syllables.syllables.concat();
+                            let explanation = word.explanation();
+                            let breakdown = word.syllables.to_string();
+                            view! {
+                                <p class="cloze-progress">
+                                    {format!("{} / {} · ✓ {}", index.get() + 1, ROUND_SIZE, score.get())}
+                                </p>
+                                <p class="accent-prompt">"Which syllable takes the tilde?"</p>
+                                <div class="accent-syllables">{syllables}</div>
+                                <button
+                                    class=choice_class(None)
+                                    disabled=move || answer.get().is_some()
+                                    on:click=move |_| pick(None)
+                                >
+                                    <kbd>"0"</kbd>
+                                    "No tilde"
+                                </button>
+
+                                {move || answer.get().map(|picked| {
+                                    let correct = picked == word.tilde;
+                                    view! {
+                                        <div class="cloze-feedback">
+                                            <p class=if correct { "cloze-result correct" } else { "cloze-result incorrect" }>
+                                                {if correct { "✓ " } else { "✗ " }}
+                                                <strong>{spelled.clone()}</strong>
+                                                " · " {breakdown.clone()}
+                                            </p>
+                                            <p class="accent-explanation">{explanation.clone()}</p>
+                                            <button class="nav-btn" on:click=move |_| go_next()>"Next →"</button>
+                                        </div>
+                                    }
+                                })}
+                            }.into_any()
+                        }
+                        None => view! {
+                            <div class="cloze-summary">
+                                <p class="cloze-score">{format!("{} / {}", score.get(), ROUND_SIZE)}</p>
+                                <p>"words placed correctly"</p>
+                                <button class="nav-btn" on:click=next_round>"Next round"</button>
+                                <A href="/grammar" attr:class="error-link">"Back to grammar"</A>
+                            </div>
+                        }.into_any(),
+                    },
+                }}
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,221 @@
+use crate::components::DataErrorMessage;
+use crate::core::events::EventKind;
+use crate::core::gender::{ArticleForm, Gender, GenderRule, Noun};
+use crate::core::inflection::{GENDERLESS_ADJECTIVES, adjective_form, noun_phrase};
+use crate::core::keymap::{KeyBinding, use_key_bindings};
+use crate::core::text::{normalize, shuffle_key};
+use crate::core::{EventLogContext, MistakesContext, ProgressContext};
+use crate::data::{DataError, LearningDirection, load_vocabulary_stage};
+use leptos::ev::SubmitEvent;
+use leptos::prelude::*;
+use leptos_router::components::A;
+
+/// Number of phrases asked in one round
+const ROUND_SIZE: usize = 10;
+
+/// `-o/a` words that go before the noun, so they don't fit the drill
+const DETERMINERS: [&str; 6] = ["uno", "mucho", "poco", "todo", "otro", "mismo"];
+
+/// Nouns and adjectives (masculine singular) of the given stages, each in a
+/// fixed shuffled order
+fn practice_words(stages: &[u32]) -> Result<(Vec<Noun>, Vec<String>), DataError> {
+    let mut nouns: Vec<Noun> = Vec::new();
+    let mut adjectives: Vec<String> = Vec::new();
+    for &stage in stages {
+        for card in load_vocabulary_stage(stage, "es")? {
+            if let Some(noun) = Noun::from_card(&card) {
+                if !nouns.iter().any(|n| n.word == noun.word) {
+                    nouns.push(noun);
+                }
+                continue;
+            }
+            let adjective = match card.word.strip_suffix("/a") {
+                Some(masculine) if masculine.ends_with('o') => masculine,
+                _ if GENDERLESS_ADJECTIVES.contains(&card.word.as_str()) => card.word.as_str(),
+                _ => continue,
+            };
+            if !DETERMINERS.contains(&adjective) && !adjectives.iter().any(|a| a == adjective) {
+                adjectives.push(adjective.to_string());
+            }
+        }
+    }
+    nouns.sort_by_key(|n| shuffle_key(&n.word));
+    adjectives.sort_by_key(|a| shuffle_key(a));
+    Ok((nouns, adjectives))
+}
+
+/// Why the phrase is written the way it is
+fn agreement_hint(noun: &Noun, adjective: &str) -> String {
+    let gender = match noun.gender {
+        Gender::Masculine => "masculine",
+        Gender::Feminine => "feminine",
+    };
+    if adjective_form(adjective, Gender::Feminine, false) == adjective {
+        format!(
+            "{} has the same form for both genders; only the number changes.",
+            adjective
+        )
+    } else if noun.rule() == GenderRule::StressedA {
+        format!(
+            "{} takes el in the singular but is feminine, so the adjective is feminine too.",
+            noun.word
+        )
+    } else {
+        format!(
+            "{} is {}, so the adjective is {} too.",
+            noun.word, gender, gender
+        )
+    }
+}
+
+/// Agreement practice - Make a noun and an adjective agree in gender and number
+#[component]
+pub fn AgreementPractice() -> impl IntoView {
+    let progress = expect_context::<ProgressContext>();
+    let events_ctx = expect_context::<EventLogContext>();
+    let mistakes_ctx = expect_context::<MistakesContext>();
+    let words = Memo::new(move |_| practice_words(&progress.studied_stages()));
+
+    // State management
+    let (round, set_round) = signal(0usize);
+    let (index, set_index) = signal(0usize);
+    let (fill, set_fill) = signal(String::new());
+    let (graded, set_graded) = signal(None::<bool>);
+    let (score, set_score) = signal(0usize);
+
+    // The noun, the adjective and whether the phrase is plural
+    let current = move || {
+        words.read().as_ref().ok().and_then(|(nouns, adjectives)| {
+            let position = round.get() * ROUND_SIZE + index.get();
+            (index.get() < ROUND_SIZE && !nouns.is_empty() && !adjectives.is_empty()).then(|| {
+                (
+                    nouns[position % nouns.len()].clone(),
+                    adjectives[position % adjectives.len()].clone(),
+                    position % 3 != 2,
+                )
+            })
+        })
+    };
+
+    let grade = move |answer: String| {
+        if graded.get_untracked().is_some() {
+            return;
+        }
+        if let Some((noun, adjective, is_plural)) = current() {
+            let correct =
+                normalize(&answer) == normalize(&noun_phrase(&noun, &adjective, is_plural));
+            if correct {
+                set_score.update(|s| *s += 1);
+            }
+            // The noun's card stands for the phrase; adjectives are only
+            // known by their form
+            events_ctx.record(noun.card_id, EventKind::Answer { correct });
+            mistakes_ctx.record(noun.card_id, correct);
+            set_graded.set(Some(correct));
+        }
+    };
+
+    let go_next = move || {
+        if graded.get_untracked().is_some() {
+            set_index.update(|i| *i += 1);
+            set_fill.set(String::new());
+            set_graded.set(None);
+        }
+    };
+
+    let next_round = move |_| {
+        set_round.update(|r| *r += 1);
+        set_index.set(0);
+        set_score.set(0);
+        set_fill.set(String::new());
+        set_graded.set(None);
+    };
+
+    use_key_bindings(vec![KeyBinding::new(
+        &["ArrowRight"],
+        "→",
+        "Next phrase",
+        go_next,
+    )]);
+
+    view! {
+        <div class="page-container">
+            <header class="page-header">
+                <A href="/grammar" attr:class="back-button">"❮"</A>
+                <h1>"Agreement"</h1>
+            </header>
+
+            <div class="cloze-container">
+                {move || match words.get() {
+                    Err(e) => view! {
+                        <DataErrorMessage error=e direction=LearningDirection::SpanishToEnglish/>
+                    }.into_any(),
+                    Ok((nouns, adjectives)) if nouns.is_empty() || adjectives.is_empty() => view! {
+                        <div class="cloze-summary">
+                            <p>"The drill uses the nouns and adjectives of the vocabulary stages you have studied."</p>
+                            <A href="/vocabulary" attr:class="nav-btn error-action">"Study a stage"</A>
+                        </div>
+                    }.into_any(),
+                    Ok(_) => match current() {
+                        Some((noun, adjective, is_plural)) => {
+                            let answer = noun_phrase(&noun, &adjective, is_plural);
+                            let hint = agreement_hint(&noun, &adjective);
+                            view! {
+                                <p class="cloze-progress">
+                                    {format!("{} / {} · ✓ {}", index.get() + 1, ROUND_SIZE, score.get())}
+                                </p>
+                                <p class="cloze-sentence">
+                                    {format!("{} {} + {}", noun.article(ArticleForm::Definite), noun.word, adjective)}
+                                </p>
+                                <p class="cloze-hint">{if is_plural { "→ plural" } else { "→ singular" }}</p>
+
+                                <form
+                                    class="cloze-form"
+                                    on:submit=move |ev: SubmitEvent| {
+                                        ev.prevent_default();
+                                        if !fill.get_untracked().trim().is_empty() {
+                                            grade(fill.get_untracked());
+                                        }
+                                    }
+                                >
+                                    <input
+                                        class="cloze-input"
+                                        type="text"
+                                        placeholder="Article, noun and adjective"
+                                        autocomplete="off"
+                                        autocapitalize="off"
+                                        prop:value=fill
+                                        prop:disabled=move || graded.get().is_some()
+                                        on:input=move |ev| set_fill.set(event_target_value(&ev))
+                                    />
+                                    <button class="nav-btn" type="submit" disabled=move || graded.get().is_some()>
+                                        "Check"
+                                    </button>
+                                </form>
+
+                                {move || graded.get().map(|correct| view! {
+                                    <div class="cloze-feedback">
+                                        {if correct {
+                                            view! { <p class="cloze-result correct">"✓ " {answer.clone()}</p> }.into_any()
+                                        } else {
+                                            view! { <p class="cloze-result incorrect">"✗ The answer is " <strong>{answer.clone()}</strong></p> }.into_any()
+                                        }}
+                                        <p class="accent-explanation">{hint.clone()}</p>
+                                        <button class="nav-btn" on:click=move |_| go_next()>"Next →"</button>
+                                    </div>
+                                })}
+                            }.into_any()
+                        }
+                        None => view! {
+                            <div class="cloze-summary">
+                                <p class="cloze-score">{format!("{} / {}", score.get(), ROUND_SIZE)}</p>
+                                <p>"phrases made to agree"</p>
+                                <button class="nav-btn" on:click=next_round>"Next round"</button>
+                            </div>
+                        }.into_any(),
+                    },
+                }}
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,245 @@
+use crate::components::DataErrorMessage;
+use crate::core::cloze::{Cloze, cloze_choices, generate_cloze};
+use crate::core::events::EventKind;
+use crate::core::keymap::{KeyBinding, use_key_bindings};
+use crate::core::language::Language;
+use crate::core::{EventLogContext, MistakesContext};
+use crate::data::{DirectionMode, LearningDirection, load_vocabulary_stage};
+use leptos::ev::SubmitEvent;
+use leptos::prelude::*;
+use leptos_router::{components::A, hooks::use_params_map, hooks::use_query_map};
+
+/// Number of answer choices offered for each sentence
+const CHOICE_COUNT: usize = 4;
+
+/// Cloze practice page - Fill in the headword blanked from each example
+#[component]
+pub fn ClozePractice() -> impl IntoView {
+    let params = use_params_map();
+    let query = use_query_map();
+    let events_ctx = expect_context::<EventLogContext>();
+    let mistakes_ctx = expect_context::<MistakesContext>();
+
+    // Extract stage from URL params
+    let stage = move || {
+        params
+            .read()
+            .get("stage")
+            .and_then(|s| s.parse::<u32>().ok())
+            .unwrap_or(1)
+    };
+
+    // Extract direction mode from query params; quizzes go one way even in
+    // mixed sessions, which keep their mode in the links back
+    let mode = move || DirectionMode::from_query(query.read().get("dir").as_deref());
+    let direction = move || mode().primary();
+
+    // Sentences of the stage whose headword could be blanked
+    let clozes = Memo::new(move |_| {
+        let spanish = load_vocabulary_stage(stage(), "es")?;
+        let english = load_vocabulary_stage(stage(), "en")?;
+        let direction = direction();
+        Ok(spanish
+            .iter()
+            .zip(&english)
+            .filter_map(|(es, en)| match direction {
+                LearningDirection::SpanishToEnglish => {
+                    generate_cloze(en, es, direction.target_language())
+                }
+                LearningDirection::EnglishToSpanish => {
+                    generate_cloze(es, en, direction.target_language())
+                }
+            })
+            .collect::<Vec<Cloze>>())
+    });
+
+    // State management
+    let (index, set_index) = signal(0usize);
+    let (fill, set_fill) = signal(String::new());
+    let (graded, set_graded) = signal(None::<bool>);
+    let (score, set_score) = signal(0usize);
+
+    // Restart when the stage or direction changes
+    Effect::new(move |_| {
+        clozes.track();
+        set_index.set(0);
+        set_score.set(0);
+        set_fill.set(String::new());
+        set_graded.set(None);
+    });
+
+    let current = move || {
+        clozes
+            .read()
+            .as_ref()
+            .ok()
+            .and_then(|c| c.get(index.get()).cloned())
+    };
+
+    let choices = move || {
+        clozes
+            .read()
+            .as_ref()
+            .map(|c| cloze_choices(c, index.get(), CHOICE_COUNT))
+            .unwrap_or_default()
+    };
+
+    let grade = move |answer: String| {
+        if graded.get_untracked().is_some() {
+            return;
+        }
+        if let Some(cloze) = current() {
+            let correct = cloze.check(&answer);
+            events_ctx.record(cloze.card_id, EventKind::Answer { correct });
+            mistakes_ctx.record(cloze.card_id, correct);
+            if correct {
+                set_score.update(|s| *s += 1);
+            }
+            set_fill.set(answer);
+            set_graded.set(Some(correct));
+        }
+    };
+
+    let go_next = move || {
+        if graded.get_untracked().is_some() {
+            set_index.update(|i| *i += 1);
+            set_fill.set(String::new());
+            set_graded.set(None);
+        }
+    };
+
+    let restart = move |_| {
+        set_index.set(0);
+        set_score.set(0);
+        set_fill.set(String::new());
+        set_graded.set(None);
+    };
+
+    let choose = move |n: usize| {
+        move || {
+            if let Some(choice) = choices().get(n) {
+                grade(choice.clone());
+            }
+        }
+    };
+    use_key_bindings(vec![
+        KeyBinding::new(&["1"], "1", "Choose the first answer", choose(0)),
+        KeyBinding::new(&["2"], "2", "Choose the second answer", choose(1)),
+        KeyBinding::new(&["3"], "3", "Choose the third answer", choose(2)),
+        KeyBinding::new(&["4"], "4", "Choose the fourth answer", choose(3)),
+        KeyBinding::new(&["ArrowRight"], "→", "Next sentence", go_next),
+    ]);
+
+    let hint_flag = move || match direction().target_language() {
+        Language::Spanish => "🇬🇧",
+        Language::English => "🇪🇸",
+    };
+
+    view! {
+        <div class="page-container">
+            <header class="page-header">
+                <A href={move || format!("/vocabulary/{}?dir={}", stage(), mode().as_query())} attr:class="back-button">"❮"</A>
+                <h1>"Fill in the blank"</h1>
+            </header>
+
+            <div class="cloze-container">
+                {move || match clozes.get() {
+                    Err(e) => view! {
+                        <DataErrorMessage error=e direction=direction()/>
+                    }.into_any(),
+                    Ok(all) => match current() {
+                        Some(cloze) => view! {
+                            <p class="cloze-progress">
+                                {format!("{} / {} · ✓ {}", index.get() + 1, all.len(), score.get())}
+                            </p>
+
+                            <p class="cloze-sentence">
+                                {cloze.before.clone()}
+                                <span class="cloze-blank">
+                                    {if graded.get().is_some() { cloze.answer.clone() } else { "_____".to_string() }}
+                                </span>
+                                {cloze.after.clone()}
+                            </p>
+                            <p class="cloze-hint">{hint_flag()} " " {cloze.hint.clone()}</p>
+
+                            <form
+                                class="cloze-form"
+                                on:submit=move |ev: SubmitEvent| {
+                                    ev.prevent_default();
+                                    if !fill.get_untracked().trim().is_empty() {
+                                        grade(fill.get_untracked());
+                                    }
+                                }
+                            >
+                                <input
+                                    class="cloze-input"
+                                    type="text"
+                                    placeholder="Type the missing word"
+                                    autocomplete="off"
+                                    autocapitalize="off"
+                                    prop:value=fill
+                                    prop:disabled=move || graded.get().is_some()
+                                    on:input=move |ev| set_fill.set(event_target_value(&ev))
+                                />
+                                <button class="nav-btn" type="submit" disabled=move || graded.get().is_some()>
+                                    "Check"
+                                </button>
+                            </form>
+
+                            <div class="cloze-choices">
+                                {choices().into_iter().enumerate().map(|(n, choice)| {
+                                    let state = {
+                                        let cloze = cloze.clone();
+                                        let choice = choice.clone();
+                                        move || match graded.get() {
+                                            Some(_) if cloze.check(&choice) => "cloze-choice correct",
+                                            Some(false) if fill.get() == choice => "cloze-choice incorrect",
+                                            _ => "cloze-choice",
+                                        }
+                                    };
+                                    let label = choice.clone();
+                                    view! {
+                                        <button
+                                            class=state
+                                            disabled=move || graded.get().is_some()
+                                            on:click=move |_| grade(choice.clone())
+                                        >
+                                            <kbd>{(n + 1).to_string()}</kbd>
+                                            {label}
+                                        </button>
+                                    }
+                                }).collect::<Vec<_>>()}
+                            </div>
+
+                            {move || graded.get().map(|correct| view! {
+                                <div class="cloze-feedback">
+                                    {if correct {
+                                        view! { <p class="cloze-result correct">"✓ Correct"</p> }.into_any()
+                                    } else {
+                                        view! { <p class="cloze-result incorrect">"✗ The answer is " <strong>{cloze.answer.clone()}</strong></p> }.into_any()
+                                    }}
+                                    <button class="nav-btn" on:click=move |_| go_next()>"Next →"</button>
+                                </div>
+                            })}
+                        }.into_any(),
+                        None if all.is_empty() => view! {
+                            <div class="error-message">
+                                <p>"No sentences to practice in this stage"</p>
+                            </div>
+                        }.into_any(),
+                        None => view! {
+                            <div class="cloze-summary">
+                                <p class="cloze-score">{format!("{} / {}", score.get(), all.len())}</p>
+                                <p>"sentences filled in correctly"</p>
+                                <button class="nav-btn" on:click=restart>"Practice again"</button>
+                                <A href={format!("/vocabulary/{}?dir={}", stage(), mode().as_query())} attr:class="error-link">
+                                    "Back to the cards"
+                                </A>
+                            </div>
+                        }.into_any(),
+                    },
+                }}
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,225 @@
+use crate::components::DataErrorMessage;
+use crate::core::dictation::{WordDiff, align_words, is_perfect};
+use crate::core::events::EventKind;
+use crate::core::keymap::{KeyBinding, use_key_bindings};
+use crate::core::language::Language;
+use crate::core::speech;
+use crate::core::{EventLogContext, MistakesContext};
+use crate::data::{DirectionMode, load_vocabulary_stage};
+use leptos::ev::SubmitEvent;
+use leptos::prelude::*;
+use leptos_router::{components::A, hooks::use_params_map, hooks::use_query_map};
+
+/// Dictation page - Hear each example sentence of a stage and type it
+#[component]
+pub fn DictationPractice() -> impl IntoView {
+    let params = use_params_map();
+    let query = use_query_map();
+    let events_ctx = expect_context::<EventLogContext>();
+    let mistakes_ctx = expect_context::<MistakesContext>();
+
+    // Extract stage from URL params
+    let stage = move || {
+        params
+            .read()
+            .get("stage")
+            .and_then(|s| s.parse::<u32>().ok())
+            .unwrap_or(1)
+    };
+
+    // Extract direction mode from query params; quizzes go one way even in
+    // mixed sessions, which keep their mode in the links back
+    let mode = move || DirectionMode::from_query(query.read().get("dir").as_deref());
+    let direction = move || mode().primary();
+
+    // Example sentences of the stage in the language being recalled, with
+    // the global ID of their card
+    let sentences = Memo::new(move |_| {
+        let language = match direction().target_language() {
+            Language::Spanish => "es",
+            Language::English => "en",
+        };
+        load_vocabulary_stage(stage(), language).map(|cards| {
+            cards
+                .into_iter()
+                .map(|c| (c.id, c.example))
+                .collect::<Vec<_>>()
+        })
+    });
+
+    // State management
+    let (index, set_index) = signal(0usize);
+    let (fill, set_fill) = signal(String::new());
+    let (graded, set_graded) = signal(None::<Vec<WordDiff>>);
+    let (score, set_score) = signal(0usize);
+
+    let current = move || {
+        sentences
+            .read()
+            .as_ref()
+            .ok()
+            .and_then(|s| s.get(index.get()).cloned())
+    };
+
+    let play = move || {
+        if let Some((_, sentence)) = sentences
+            .read_untracked()
+            .as_ref()
+            .ok()
+            .and_then(|s| s.get(index.get_untracked()))
+        {
+            speech::speak(sentence, direction().target_language().speech_code());
+        }
+    };
+
+    // Restart when the stage or direction changes
+    Effect::new(move |_| {
+        sentences.track();
+        set_index.set(0);
+        set_score.set(0);
+        set_fill.set(String::new());
+        set_graded.set(None);
+    });
+
+    // Read each new sentence aloud
+    Effect::new(move |_| {
+        index.track();
+        sentences.track();
+        play();
+    });
+
+    let grade = move |answer: String| {
+        if graded.get_untracked().is_some() {
+            return;
+        }
+        if let Some((card_id, sentence)) = current() {
+            let diffs = align_words(&sentence, &answer);
+            let correct = is_perfect(&diffs);
+            events_ctx.record(card_id, EventKind::Answer { correct });
+            mistakes_ctx.record(card_id, correct);
+            if correct {
+                set_score.update(|s| *s += 1);
+            }
+            set_graded.set(Some(diffs));
+        }
+    };
+
+    let go_next = move || {
+        if graded.get_untracked().is_some() {
+            set_index.update(|i| *i += 1);
+            set_fill.set(String::new());
+            set_graded.set(None);
+        }
+    };
+
+    let restart = move |_| {
+        set_index.set(0);
+        set_score.set(0);
+        set_fill.set(String::new());
+        set_graded.set(None);
+    };
+
+    use_key_bindings(vec![
+        KeyBinding::new(&["p", "P"], "P", "Play the sentence again", play),
+        KeyBinding::new(&["ArrowRight"], "→", "Next sentence", go_next),
+    ]);
+
+    view! {
+        <div class="page-container">
+            <header class="page-header">
+                <A href={move || format!("/vocabulary/{}?dir={}", stage(), mode().as_query())} attr:class="back-button">"❮"</A>
+                <h1>"Dictation"</h1>
+            </header>
+
+            <div class="cloze-container">
+                {move || match sentences.get() {
+                    Err(e) => view! {
+                        <DataErrorMessage error=e direction=direction()/>
+                    }.into_any(),
+                    Ok(all) => match current() {
+                        Some((_, sentence)) => view! {
+                            <p class="cloze-progress">
+                                {format!("{} / {} · ✓ {}", index.get() + 1, all.len(), score.get())}
+                            </p>
+
+                            <div class="drill-question">
+                                <button class="audio-button" title="Play again" on:click=move |_| play()>"🔊"</button>
+                            </div>
+
+                            <form
+                                class="cloze-form"
+                                on:submit=move |ev: SubmitEvent| {
+                                    ev.prevent_default();
+                                    grade(fill.get_untracked());
+                                }
+                            >
+                                <input
+                                    class="cloze-input"
+                                    type="text"
+                                    placeholder="Type what you hear"
+                                    autocomplete="off"
+                                    autocapitalize="off"
+                                    prop:value=fill
+                                    prop:disabled=move || graded.get().is_some()
+                                    on:input=move |ev| set_fill.set(event_target_value(&ev))
+                                />
+                                <button class="nav-btn" type="submit" disabled=move || graded.get().is_some()>
+                                    "Check"
+                                </button>
+                            </form>
+
+                            {move || graded.get().map(|diffs| {
+                                let perfect = is_perfect(&diffs);
+                                view! {
+                                    <div class="cloze-feedback">
+                                        <p class="dictation-diff">
+                                            {diffs.into_iter().map(|diff| match diff {
+                                                WordDiff::Correct(word) => view! {
+                                                    <span class="dictation-word">{word}</span>
+                                                }.into_any(),
+                                                WordDiff::Misspelled { expected, typed } => view! {
+                                                    <span class="dictation-word misspelled">
+                                                        <del>{typed}</del>
+                                                        " "
+                                                        {expected}
+                                                    </span>
+                                                }.into_any(),
+                                                WordDiff::Missing(word) => view! {
+                                                    <span class="dictation-word missing">{word}</span>
+                                                }.into_any(),
+                                                WordDiff::Extra(word) => view! {
+                                                    <span class="dictation-word extra"><del>{word}</del></span>
+                                                }.into_any(),
+                                            }).collect::<Vec<_>>()}
+                                        </p>
+                                        {if perfect {
+                                            view! { <p class="cloze-result correct">"✓ Every word right"</p> }.into_any()
+                                        } else {
+                                            view! { <p class="cloze-result incorrect">{sentence.clone()}</p> }.into_any()
+                                        }}
+                                        <button class="nav-btn" on:click=move |_| go_next()>"Next →"</button>
+                                    </div>
+                                }
+                            })}
+                        }.into_any(),
+                        None if all.is_empty() => view! {
+                            <div class="error-message">
+                                <p>"No sentences to practice in this stage"</p>
+                            </div>
+                        }.into_any(),
+                        None => view! {
+                            <div class="cloze-summary">
+                                <p class="cloze-score">{format!("{} / {}", score.get(), all.len())}</p>
+                                <p>"sentences written without a mistake"</p>
+                                <button class="nav-btn" on:click=restart>"Practice again"</button>
+                                <A href={format!("/vocabulary/{}?dir={}", stage(), mode().as_query())} attr:class="error-link">
+                                    "Back to the cards"
+                                </A>
+                            </div>
+                        }.into_any(),
+                    },
+                }}
+            </div>
+        </div>
+    }
+}
@@ -1,6 +1,10 @@
-use crate::components::VocabularyCard;
-use crate::core::FavoritesContext;
-use crate::data::{LearningDirection, get_card_pair};
+use crate::components::{DataErrorMessage, QrCodeImage, VocabularyCard};
+use crate::core::events::EventKind;
+use crate::core::keymap::{KeyBinding, use_key_bindings};
+use crate::core::mastery::card_direction;
+use crate::core::share::encode_favorites;
+use crate::core::{EventLogContext, FavoritesContext, MasteryContext, SettingsContext};
+use crate::data::{DataError, DirectionMode, card_index_for_id, get_card_pair, stage_for_card_id};
 use leptos::prelude::*;
 use leptos_router::components::A;
 use leptos_router::hooks::use_query_map;
@@ -9,75 +13,89 @@ use leptos_router::hooks::use_query_map;
 #[component]
 pub fn Favorites() -> impl IntoView {
     let favorites_ctx = expect_context::<FavoritesContext>();
+    let events_ctx = expect_context::<EventLogContext>();
+    let settings_ctx = expect_context::<SettingsContext>();
+    let mastery_ctx = expect_context::<MasteryContext>();
     let query = use_query_map();
 
-    // Extract direction from query params
-    let direction = move || {
-        query
-            .read()
-            .get("dir")
-            .map(|d| {
-                if d == "en-to-es" {
-                    LearningDirection::EnglishToSpanish
-                } else {
-                    LearningDirection::SpanishToEnglish
-                }
-            })
-            .unwrap_or(LearningDirection::SpanishToEnglish)
-    };
+    // Extract direction mode from query params
+    let mode = move || DirectionMode::from_query(query.read().get("dir").as_deref());
 
     // State management
     let (card_index, set_card_index) = signal(0usize);
+    let (show_share, set_show_share) = signal(false);
 
     // Get filtered list of valid favorites, sorted by card_id
     let favorite_cards = move || {
         let all = favorites_ctx.get_all();
         let mut filtered: Vec<_> = all
             .into_iter()
-            .filter(|(stage, card_id)| {
-                // Stage 1: IDs 1-20, Stage 2: IDs 21-40, Stage 3: IDs 41-60, etc.
-                match stage {
-                    i if (1..=21).contains(i) => {
-                        let start_id = (i - 1) * 20 + 1;
-                        let end_id = i * 20;
-                        *card_id >= start_id && *card_id <= end_id
-                    }
-                    _ => false,
-                }
-            })
+            // Stage 1: IDs 1-20, Stage 2: IDs 21-40, Stage 3: IDs 41-60, etc.
+            .filter(|(stage, card_id)| stage_for_card_id(*card_id) == Some(*stage))
             .collect();
         filtered.sort_by_key(|(_, card_id)| *card_id);
         filtered
     };
 
-    // Get current favorite card
+    // Global ID of the card shown
+    let shown_card_id = Memo::new(move |_| {
+        let cards = favorite_cards();
+        cards
+            .get(card_index.get())
+            .or(cards.last())
+            .map(|(_, card_id)| *card_id)
+    });
+
+    // Direction of the card shown; in mixed sessions it is picked once per
+    // card so it stays put while the card is shown
+    let direction = Memo::new(move |_| {
+        card_direction(
+            mode(),
+            settings_ctx.settings.read_untracked().mixed_order,
+            card_index.get(),
+            shown_card_id.get(),
+            &mastery_ctx.mastery.read_untracked(),
+        )
+    });
+    let direction = move || direction.get();
+
+    // Get current favorite card, or None when there are no favorites
     let current_card = move || {
         let cards = favorite_cards();
-        if cards.is_empty() || card_index.get() >= cards.len() {
-            return Err("No favorites available".to_string());
-        }
-        let (stage, card_id) = cards[card_index.get()];
-        let card_idx = match stage {
-            i if (1..=21).contains(&i) => (card_id - ((i - 1) * 20 + 1)) as usize,
-            _ => return Err("Invalid stage".to_string()),
-        };
-        get_card_pair(stage, card_idx, direction()).map(|(source, target)| (stage, source, target))
+        let (stage, card_id) = cards.get(card_index.get()).or(cards.last()).copied()?;
+        let card = card_index_for_id(card_id)
+            .ok_or(DataError::UnknownStage { stage })
+            .and_then(|card_idx| get_card_pair(stage, card_idx, direction()))
+            .map(|(source, target)| (stage, source, target));
+        Some(card)
     };
 
+    // Log each card shown
+    Effect::new(move |_| {
+        if let Some(card_id) = shown_card_id.get() {
+            events_ctx.record(card_id, EventKind::View);
+        }
+    });
+
     // Navigation handlers
-    let go_next = move |_| {
+    let go_next = move || {
         let cards = favorite_cards();
-        if card_index.get() < cards.len() - 1 {
+        if card_index.get() + 1 < cards.len() {
             set_card_index.update(|i| *i += 1);
         }
     };
 
-    let go_prev = move |_| {
+    let go_prev = move || {
         if card_index.get() > 0 {
             set_card_index.update(|i| *i -= 1);
         }
     };
 
+    use_key_bindings(vec![
+        KeyBinding::new(&["ArrowLeft"], "←", "Previous card", go_prev),
+        KeyBinding::new(&["ArrowRight"], "→", "Next card", go_next),
+    ]);
+
     // Toggle favorite (remove from favorites)
     let toggle_favorite = move |_| {
         let cards = favorite_cards();
@@ -96,68 +114,95 @@ pub fn Favorites() -> impl IntoView {
     view! {
         <div class="page-container">
             <header class="page-header">
-                <A href={move || format!("/vocabulary?dir={}", if direction() == LearningDirection::EnglishToSpanish { "en-to-es" } else { "es-to-en" })} attr:class="back-button">"❮"</A>
+                <A href={move || format!("/vocabulary?dir={}", mode().as_query())} attr:class="back-button">"❮"</A>
                 <h1>"Favorites"</h1>
+                <button
+                    class="header-action"
+                    on:click=move |_| set_show_share.update(|s| *s = !*s)
+                    disabled={move || favorite_cards().is_empty()}
+                >
+                    "Share"
+                </button>
             </header>
 
+            {move || (show_share.get() && !favorite_cards().is_empty()).then(|| {
+                let link = share_link(&favorite_cards());
+                view! {
+                    <div class="share-panel">
+                        <QrCodeImage text=link.clone()/>
+                        <input class="share-link" readonly value=link/>
+                        <p>"Scan or open this link on another device to import these favorites"</p>
+                    </div>
+                }
+            })}
+
             <div class="card-learning-container">
                 {move || {
-                    let cards = favorite_cards();
-
-                    if cards.is_empty() {
-                        view! {
+                    match current_card() {
+                        None => view! {
                             <div class="error-message">
                                 <p>"No favorites yet!"</p>
                                 <p style="color: #666; font-size: 1rem;">"Add cards to favorites by clicking the ☆ icon"</p>
                             </div>
-                        }.into_any()
-                    } else {
-                        match current_card() {
-                            Ok((stage, source, target)) => {
-                                view! {
-                                    <div class="card-wrapper">
-                                        <VocabularyCard
-                                            source_word={source.word.clone()}
-                                            source_example={source.example.clone()}
-                                            target_word={target.word.clone()}
-                                            target_example={target.example.clone()}
-                                            card_index={card_index.get()}
-                                            card_count={favorite_cards().len()}
-                                            is_favorite={true}
-                                            direction={direction()}
-                                            stage=stage
-                                            on_toggle_favorite=move || toggle_favorite(())
-                                        />
-
-                                        <div class="card-navigation">
-                                            <button
-                                                class="nav-btn"
-                                                on:click=go_prev
-                                                disabled={move || card_index.get() == 0}
-                                            >
-                                                "← Previous"
-                                            </button>
-                                            <button
-                                                class="nav-btn"
-                                                on:click=go_next
-                                                disabled={move || card_index.get() >= favorite_cards().len() - 1}
-                                            >
-                                                "Next →"
-                                            </button>
-                                        </div>
+                        }.into_any(),
+                        Some(Ok((stage, source, target))) => {
+                            view! {
+                                <div class="card-wrapper">
+                                    <VocabularyCard
+                                        source_word={source.word.clone()}
+                                        source_example={source.example.clone()}
+                                        target_word={target.word.clone()}
+                                        target_example={target.example.clone()}
+                                        card_index={card_index.get()}
+                                        card_count={favorite_cards().len()}
+                                        is_favorite={true}
+                                        direction={direction()}
+                                        stage=stage
+                                        on_toggle_favorite=move || toggle_favorite(())
+                                        on_previous=Callback::new(move |_| go_prev())
+                                        on_next=Callback::new(move |_| go_next())
+                                        on_reveal_translation=Callback::new(move |_| {
+                                            if let Some(card_id) = shown_card_id.get_untracked() {
+                                                events_ctx.record(card_id, EventKind::Reveal);
+                                            }
+                                        })
+                                    />
+
+                                    <div class="card-navigation">
+                                        <button
+                                            class="nav-btn"
+                                            on:click=move |_| go_prev()
+                                            disabled={move || card_index.get() == 0}
+                                        >
+                                            "← Previous"
+                                        </button>
+                                        <button
+                                            class="nav-btn"
+                                            on:click=move |_| go_next()
+                                            disabled={move || card_index.get() >= favorite_cards().len() - 1}
+                                        >
+                                            "Next →"
+                                        </button>
                                     </div>
-                                }.into_any()
-                            }
-                            Err(e) => view! {
-                                <div class="error-message">
-                                    <p>"Error loading card: " {e}</p>
-                                    <A href="/vocabulary" attr:class="back-button">"❮"</A>
                                 </div>
                             }.into_any()
                         }
+                        Some(Err(e)) => view! {
+                            <DataErrorMessage error=e direction=direction()/>
+                        }.into_any()
                     }
                 }}
             </div>
         </div>
     }
 }
+
+/// Build an absolute import link for the given favorites
+fn share_link(favorites: &[(u32, u32)]) -> String {
+    let origin = window().location().origin().unwrap_or_default();
+    format!(
+        "{}/vocabulary/favorites/import?d={}",
+        origin,
+        encode_favorites(favorites)
+    )
+}
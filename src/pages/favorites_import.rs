@@ -0,0 +1,86 @@
+use crate::core::FavoritesContext;
+use crate::core::share::decode_favorites;
+use crate::data::{LearningDirection, card_index_for_id, get_card_pair};
+use leptos::prelude::*;
+use leptos_router::components::A;
+use leptos_router::hooks::{use_navigate, use_query_map};
+
+/// Favorites import page - Previews a shared favorites list and merges it
+#[component]
+pub fn FavoritesImport() -> impl IntoView {
+    let favorites_ctx = expect_context::<FavoritesContext>();
+    let query = use_query_map();
+    let navigate = use_navigate();
+
+    // Decode the shared list from the `d` query param
+    let shared_cards = move || {
+        query
+            .read()
+            .get("d")
+            .ok_or_else(|| "No share code in this link".to_string())
+            .and_then(|code| decode_favorites(&code))
+    };
+
+    let merge = move |_| {
+        if let Ok(cards) = shared_cards() {
+            favorites_ctx.merge(&cards);
+            navigate("/vocabulary/favorites", Default::default());
+        }
+    };
+
+    view! {
+        <div class="page-container">
+            <header class="page-header">
+                <A href="/vocabulary" attr:class="back-button">"❮"</A>
+                <h1>"Import"</h1>
+            </header>
+
+            <div class="card-learning-container">
+                {move || match shared_cards() {
+                    Ok(cards) if !cards.is_empty() => {
+                        let new_count = cards
+                            .iter()
+                            .filter(|(stage, card_id)| !favorites_ctx.is_favorite(*stage, *card_id))
+                            .count();
+                        let merge = merge.clone();
+                        view! {
+                            <div class="card-wrapper">
+                                <p class="card-progress">
+                                    {format!("{} shared cards, {} new", cards.len(), new_count)}
+                                </p>
+                                <ul class="import-preview">
+                                    {cards.into_iter().map(|(stage, card_id)| {
+                                        let label = card_index_for_id(card_id)
+                                            .and_then(|idx| get_card_pair(stage, idx, LearningDirection::SpanishToEnglish).ok())
+                                            .map(|(source, target)| format!("{} — {}", source.word, target.word))
+                                            .unwrap_or_else(|| format!("Card {}", card_id));
+                                        view! {
+                                            <li>
+                                                <span>{label}</span>
+                                                <span class="import-stage">{format!("Stage {}", stage)}</span>
+                                            </li>
+                                        }
+                                    }).collect::<Vec<_>>()}
+                                </ul>
+                                <button class="nav-btn" on:click=merge disabled={new_count == 0}>
+                                    "Add to Favorites"
+                                </button>
+                            </div>
+                        }.into_any()
+                    }
+                    Ok(_) => view! {
+                        <div class="error-message">
+                            <p>"This link doesn't contain any favorites"</p>
+                        </div>
+                    }.into_any(),
+                    Err(e) => view! {
+                        <div class="error-message">
+                            <p>"Could not read shared favorites: " {e}</p>
+                            <A href="/vocabulary" attr:class="back-button">"❮"</A>
+                        </div>
+                    }.into_any(),
+                }}
+            </div>
+        </div>
+    }
+}
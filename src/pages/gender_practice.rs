@@ -0,0 +1,190 @@
+use crate::components::DataErrorMessage;
+use crate::core::events::EventKind;
+use crate::core::gender::{ArticleForm, GenderStats, Noun};
+use crate::core::keymap::{KeyBinding, use_key_bindings};
+use crate::core::text::shuffle_key;
+use crate::core::{EventLogContext, MistakesContext, ProgressContext};
+use crate::data::{DataError, LearningDirection, load_vocabulary_stage};
+use leptos::prelude::*;
+use leptos_router::components::A;
+
+/// Number of nouns asked in one round
+const ROUND_SIZE: usize = 10;
+
+/// Distinct nouns of the given stages, in a fixed shuffled order
+fn practice_nouns(stages: &[u32]) -> Result<Vec<Noun>, DataError> {
+    let mut nouns: Vec<Noun> = Vec::new();
+    for &stage in stages {
+        for card in load_vocabulary_stage(stage, "es")? {
+            if let Some(noun) = Noun::from_card(&card)
+                && !nouns.iter().any(|n| n.word == noun.word)
+            {
+                nouns.push(noun);
+            }
+        }
+    }
+    nouns.sort_by_key(|n| shuffle_key(&n.word));
+    Ok(nouns)
+}
+
+/// Gender practice - Pick the article for nouns from the studied stages
+#[component]
+pub fn GenderPractice() -> impl IntoView {
+    let progress = expect_context::<ProgressContext>();
+    let events_ctx = expect_context::<EventLogContext>();
+    let mistakes_ctx = expect_context::<MistakesContext>();
+    let nouns = Memo::new(move |_| practice_nouns(&progress.studied_stages()));
+    let stats = RwSignal::new(GenderStats::load());
+
+    // State management
+    let (round, set_round) = signal(0usize);
+    let (index, set_index) = signal(0usize);
+    let (answer, set_answer) = signal(None::<&'static str>);
+    let (score, set_score) = signal(0usize);
+
+    let current = move || {
+        nouns.read().as_ref().ok().and_then(|all| {
+            let position = round.get() * ROUND_SIZE + index.get();
+            (index.get() < ROUND_SIZE && !all.is_empty()).then(|| {
+                (
+                    all[position % all.len()].clone(),
+                    ArticleForm::ALL[position % ArticleForm::ALL.len()],
+                )
+            })
+        })
+    };
+
+    let pick = move |choice: usize| {
+        if answer.get_untracked().is_some() {
+            return;
+        }
+        if let Some((noun, form)) = current() {
+            let article = form.choices()[choice];
+            let correct = article == noun.article(form);
+            if correct {
+                set_score.update(|s| *s += 1);
+            }
+            stats.update(|s| s.record(noun.rule(), correct));
+            events_ctx.record(noun.card_id, EventKind::Answer { correct });
+            mistakes_ctx.record(noun.card_id, correct);
+            set_answer.set(Some(article));
+        }
+    };
+
+    let go_next = move || {
+        if answer.get_untracked().is_some() {
+            set_index.update(|i| *i += 1);
+            set_answer.set(None);
+        }
+    };
+
+    let next_round = move |_| {
+        set_round.update(|r| *r += 1);
+        set_index.set(0);
+        set_score.set(0);
+        set_answer.set(None);
+    };
+
+    use_key_bindings(vec![
+        KeyBinding::new(&["1"], "1", "Masculine article", move || pick(0)),
+        KeyBinding::new(&["2"], "2", "Feminine article", move || pick(1)),
+        KeyBinding::new(&["ArrowRight"], "→", "Next noun", go_next),
+    ]);
+
+    view! {
+        <div class="page-container">
+            <header class="page-header">
+                <A href="/grammar" attr:class="back-button">"❮"</A>
+                <h1>"Noun gender"</h1>
+            </header>
+
+            <div class="cloze-container">
+                {move || match nouns.get() {
+                    Err(e) => view! {
+                        <DataErrorMessage error=e direction=LearningDirection::SpanishToEnglish/>
+                    }.into_any(),
+                    Ok(all) if all.is_empty() => view! {
+                        <div class="cloze-summary">
+                            <p>"The drill uses the nouns of the vocabulary stages you have studied."</p>
+                            <A href="/vocabulary" attr:class="nav-btn error-action">"Study a stage"</A>
+                        </div>
+                    }.into_any(),
+                    Ok(_) => match current() {
+                        Some((noun, form)) => {
+                            let right = noun.article(form);
+                            let choices = form.choices().into_iter().enumerate().map(|(n, article)| {
+                                let state = move || match answer.get() {
+                                    Some(_) if article == right => "cloze-choice correct",
+                                    Some(picked) if picked == article => "cloze-choice incorrect",
+                                    _ => "cloze-choice",
+                                };
+                                view! {
+                                    <button
+                                        class=state
+                                        disabled=move || answer.get().is_some()
+                                        on:click=move |_| pick(n)
+                                    >
+                                        <kbd>{(n + 1).to_string()}</kbd>
+                                        {article}
+                                    </button>
+                                }
+                            }).collect::<Vec<_>>();
+                            let word = noun.form(form);
+                            let hint = noun.hint();
+                            view! {
+                                <p class="cloze-progress">
+                                    {format!("{} / {} · ✓ {}", index.get() + 1, ROUND_SIZE, score.get())}
+                                </p>
+                                <p class="cloze-sentence">
+                                    <span class="cloze-blank">
+                                        {move || answer.get().map(|_| right).unwrap_or("___")}
+                                    </span>
+                                    " " {word.clone()}
+                                </p>
+                                <div class="cloze-choices">{choices}</div>
+
+                                {move || answer.get().map(|picked| view! {
+                                    <div class="cloze-feedback">
+                                        {if picked == right {
+                                            view! { <p class="cloze-result correct">"✓ " {format!("{} {}", right, word)}</p> }.into_any()
+                                        } else {
+                                            view! { <p class="cloze-result incorrect">"✗ " {format!("{} {}", right, word)}</p> }.into_any()
+                                        }}
+                                        <p class="accent-explanation">{hint.clone()}</p>
+                                        <button class="nav-btn" on:click=move |_| go_next()>"Next →"</button>
+                                    </div>
+                                })}
+                            }.into_any()
+                        }
+                        None => view! {
+                            <div class="cloze-summary">
+                                <p class="cloze-score">{format!("{} / {}", score.get(), ROUND_SIZE)}</p>
+                                <p>"articles chosen correctly"</p>
+                                <button class="nav-btn" on:click=next_round>"Next round"</button>
+                            </div>
+                        }.into_any(),
+                    },
+                }}
+
+                {move || {
+                    let broken = stats.read().most_broken();
+                    (!broken.is_empty()).then(|| view! {
+                        <section class="gender-stats">
+                            <h2>"Rules you break most"</h2>
+                            <ul>
+                                {broken.into_iter().map(|(rule, s)| view! {
+                                    <li>
+                                        <span>{rule.name()}</span>
+                                        <span class="gender-stats-count">
+                                            {format!("{} of {} wrong", s.mistakes, s.answers)}
+                                        </span>
+                                    </li>
+                                }).collect::<Vec<_>>()}
+                            </ul>
+                        </section>
+                    })
+                }}
+            </div>
+        </div>
+    }
+}
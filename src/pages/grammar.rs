@@ -1,9 +1,26 @@
+use crate::core::language::UI_LANGUAGE;
+use crate::data::{GRAMMAR_TOPICS, load_grammar_exercises};
 use leptos::prelude::*;
 use leptos_router::components::A;
 
-/// Grammar learning page
+/// Grammar page - Lists the grammar exercises
 #[component]
 pub fn Grammar() -> impl IntoView {
+    // Topics whose exercises fail to load are left out
+    let exercise_topics = GRAMMAR_TOPICS
+        .iter()
+        .filter_map(|topic| load_grammar_exercises(topic).ok())
+        .map(|set| {
+            view! {
+                <A href=format!("/grammar/exercises/{}", set.topic) attr:class="grammar-topic">
+                    <span class="grammar-topic-icon">{set.emoji.clone()}</span>
+                    <span class="grammar-topic-title">{set.title.get(UI_LANGUAGE).to_string()}</span>
+                    <span class="grammar-topic-summary">{set.description.get(UI_LANGUAGE).to_string()}</span>
+                </A>
+            }
+        })
+        .collect::<Vec<_>>();
+
     view! {
         <div class="page-container">
             <header class="page-header">
@@ -11,8 +28,33 @@ pub fn Grammar() -> impl IntoView {
                 <h1>"Grammar"</h1>
             </header>
 
-            <div class="content">
-                <p>"Grammar exercises coming soon..."</p>
+            <div class="grammar-list">
+                <A href="/grammar/lessons" attr:class="grammar-topic">
+                    <span class="grammar-topic-icon">"📖"</span>
+                    <span class="grammar-topic-title">"Lessons"</span>
+                    <span class="grammar-topic-summary">"Explanations, tables and examples to listen to"</span>
+                </A>
+                <A href="/grammar/accents" attr:class="grammar-topic">
+                    <span class="grammar-topic-icon">"´"</span>
+                    <span class="grammar-topic-title">"Written accents"</span>
+                    <span class="grammar-topic-summary">"When and where a word takes a tilde"</span>
+                </A>
+                <A href="/grammar/gender" attr:class="grammar-topic">
+                    <span class="grammar-topic-icon">"⚥"</span>
+                    <span class="grammar-topic-title">"Noun gender"</span>
+                    <span class="grammar-topic-summary">"el or la? Articles for the nouns you have studied"</span>
+                </A>
+                <A href="/grammar/agreement" attr:class="grammar-topic">
+                    <span class="grammar-topic-icon">"🔗"</span>
+                    <span class="grammar-topic-title">"Agreement"</span>
+                    <span class="grammar-topic-summary">"Plurals and adjectives that match their noun"</span>
+                </A>
+                <A href="/grammar/numbers" attr:class="grammar-topic">
+                    <span class="grammar-topic-icon">"🔢"</span>
+                    <span class="grammar-topic-title">"Numbers, dates and times"</span>
+                    <span class="grammar-topic-summary">"Hear, read and say them in Spanish"</span>
+                </A>
+                {exercise_topics}
             </div>
         </div>
     }
@@ -0,0 +1,233 @@
+use crate::components::DataErrorMessage;
+use crate::core::keymap::{KeyBinding, use_key_bindings};
+use crate::core::language::UI_LANGUAGE;
+use crate::core::text::shuffle_key;
+use crate::data::{GAP, GrammarExercise, LearningDirection, load_grammar_exercises};
+use leptos::prelude::*;
+use leptos_router::{components::A, hooks::use_params_map};
+
+/// Number of sentences asked in one round
+const ROUND_SIZE: usize = 10;
+
+/// An exercise answered wrongly, with the choice that was picked
+#[derive(Debug, Clone, PartialEq)]
+struct Mistake {
+    exercise: GrammarExercise,
+    picked: String,
+}
+
+/// Grammar exercises page - Fill the gap with one of two easily confused words
+#[component]
+pub fn GrammarExercises() -> impl IntoView {
+    let params = use_params_map();
+
+    // Extract topic from URL params
+    let topic = move || params.read().get("topic").unwrap_or_default();
+
+    // The topic's exercises, in a fixed shuffled order
+    let set = Memo::new(move |_| {
+        load_grammar_exercises(&topic()).map(|mut set| {
+            set.exercises.sort_by_key(|e| shuffle_key(&e.sentence));
+            set
+        })
+    });
+
+    // State management
+    let queue = RwSignal::new(Vec::<GrammarExercise>::new());
+    let (round, set_round) = signal(0usize);
+    let (index, set_index) = signal(0usize);
+    let (picked, set_picked) = signal(None::<String>);
+    let mistakes = RwSignal::new(Vec::<Mistake>::new());
+
+    let start = move |exercises: Vec<GrammarExercise>| {
+        queue.set(exercises);
+        set_index.set(0);
+        set_picked.set(None);
+        mistakes.set(Vec::new());
+    };
+
+    // The next ROUND_SIZE exercises of the topic
+    let round_exercises = move |round: usize| {
+        set.read_untracked()
+            .as_ref()
+            .map(|set| {
+                let all = &set.exercises;
+                (0..ROUND_SIZE.min(all.len()))
+                    .map(|i| all[(round * ROUND_SIZE + i) % all.len()].clone())
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+
+    // Restart when the topic changes
+    Effect::new(move |_| {
+        set.track();
+        set_round.set(0);
+        start(round_exercises(0));
+    });
+
+    let current = move || queue.read().get(index.get()).cloned();
+
+    let pick = move |choice: usize| {
+        if picked.get_untracked().is_some() {
+            return;
+        }
+        if let Some(exercise) = current()
+            && let Some(word) = exercise.choices.get(choice).cloned()
+        {
+            if word != exercise.answer {
+                mistakes.update(|m| {
+                    m.push(Mistake {
+                        exercise: exercise.clone(),
+                        picked: word.clone(),
+                    })
+                });
+            }
+            set_picked.set(Some(word));
+        }
+    };
+
+    let go_next = move || {
+        if picked.get_untracked().is_some() {
+            set_index.update(|i| *i += 1);
+            set_picked.set(None);
+        }
+    };
+
+    let next_round = move |_| {
+        set_round.update(|r| *r += 1);
+        start(round_exercises(round.get_untracked()));
+    };
+
+    let retry_mistakes = move |_| {
+        let missed = mistakes.with_untracked(|m| m.iter().map(|m| m.exercise.clone()).collect());
+        start(missed);
+    };
+
+    let explanation = move |exercise: &GrammarExercise| {
+        set.read()
+            .as_ref()
+            .ok()
+            .and_then(|set| set.rule(&exercise.rule).cloned())
+            .map(|rule| {
+                format!(
+                    "{}: {}",
+                    rule.title.get(UI_LANGUAGE),
+                    rule.explanation.get(UI_LANGUAGE)
+                )
+            })
+            .unwrap_or_default()
+    };
+
+    use_key_bindings(vec![
+        KeyBinding::new(&["1"], "1", "Choose the first word", move || pick(0)),
+        KeyBinding::new(&["2"], "2", "Choose the second word", move || pick(1)),
+        KeyBinding::new(&["ArrowRight"], "→", "Next sentence", go_next),
+    ]);
+
+    view! {
+        <div class="page-container">
+            <header class="page-header">
+                <A href="/grammar" attr:class="back-button">"❮"</A>
+                <h1>
+                    {move || set.read().as_ref().map(|s| s.title.get(UI_LANGUAGE).to_string()).unwrap_or_default()}
+                </h1>
+            </header>
+
+            <div class="cloze-container">
+                {move || match set.get() {
+                    Err(e) => view! {
+                        <DataErrorMessage error=e direction=LearningDirection::SpanishToEnglish/>
+                    }.into_any(),
+                    Ok(_) => match current() {
+                        Some(exercise) => {
+                            let right = exercise.answer.clone();
+                            let gaps = exercise.sentence.split(GAP).enumerate().map(|(n, text)| {
+                                let right = right.clone();
+                                view! {
+                                    {(n > 0).then(|| view! {
+                                        <span class="cloze-blank">
+                                            {move || picked.get().map(|_| right.clone()).unwrap_or_else(|| GAP.to_string())}
+                                        </span>
+                                    })}
+                                    {text.to_string()}
+                                }
+                            }).collect::<Vec<_>>();
+                            let choices = exercise.choices.iter().cloned().enumerate().map(|(n, word)| {
+                                let right = right.clone();
+                                let label = word.clone();
+                                let state = move || match picked.get() {
+                                    Some(_) if word == right => "cloze-choice correct",
+                                    Some(p) if p == word => "cloze-choice incorrect",
+                                    _ => "cloze-choice",
+                                };
+                                view! {
+                                    <button
+                                        class=state
+                                        disabled=move || picked.get().is_some()
+                                        on:click=move |_| pick(n)
+                                    >
+                                        <kbd>{(n + 1).to_string()}</kbd>
+                                        {label}
+                                    </button>
+                                }
+                            }).collect::<Vec<_>>();
+                            let rule = explanation(&exercise);
+                            view! {
+                                <p class="cloze-progress">
+                                    {format!("{} / {} · ✗ {}", index.get() + 1, queue.read().len(), mistakes.read().len())}
+                                </p>
+                                <p class="cloze-sentence">{gaps}</p>
+                                <p class="cloze-hint">"🇬🇧 " {exercise.translation.clone()}</p>
+                                <div class="cloze-choices">{choices}</div>
+
+                                {move || picked.get().map(|p| view! {
+                                    <div class="cloze-feedback">
+                                        {if p == right {
+                                            view! { <p class="cloze-result correct">"✓ " {exercise.filled(&right)}</p> }.into_any()
+                                        } else {
+                                            view! { <p class="cloze-result incorrect">"✗ " {exercise.filled(&right)}</p> }.into_any()
+                                        }}
+                                        <p class="accent-explanation">{rule.clone()}</p>
+                                        <button class="nav-btn" on:click=move |_| go_next()>"Next →"</button>
+                                    </div>
+                                })}
+                            }.into_any()
+                        }
+                        None => {
+                            let total = queue.read().len();
+                            let missed = mistakes.get();
+                            view! {
+                                <div class="cloze-summary">
+                                    <p class="cloze-score">{format!("{} / {}", total - missed.len(), total)}</p>
+                                    <p>"sentences completed correctly"</p>
+                                    {(!missed.is_empty()).then(|| view! {
+                                        <button class="nav-btn" on:click=retry_mistakes>"Retry the mistakes"</button>
+                                    })}
+                                    <button class="nav-btn" on:click=next_round>"Next round"</button>
+                                </div>
+
+                                {(!missed.is_empty()).then(|| view! {
+                                    <section class="mistake-review">
+                                        <h2>"Review your mistakes"</h2>
+                                        <ul>
+                                            {missed.iter().map(|m| view! {
+                                                <li>
+                                                    <p class="mistake-sentence">{m.exercise.filled(&m.exercise.answer)}</p>
+                                                    <p class="mistake-picked">
+                                                        {format!("You chose {}", m.picked)}
+                                                    </p>
+                                                    <p class="accent-explanation">{explanation(&m.exercise)}</p>
+                                                </li>
+                                            }).collect::<Vec<_>>()}
+                                        </ul>
+                                    </section>
+                                })}
+                            }.into_any()
+                        }
+                    },
+                }}
+            </div>
+        </div>
+    }
+}
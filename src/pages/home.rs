@@ -1,15 +1,56 @@
+use crate::core::clock;
+use crate::core::stats::streak;
+use crate::core::{EventLogContext, SettingsContext};
 use leptos::prelude::*;
 use leptos_router::components::A;
 
 /// Home page with two main navigation buttons
 #[component]
 pub fn Home() -> impl IntoView {
+    let events_ctx = expect_context::<EventLogContext>();
+    let settings_ctx = expect_context::<SettingsContext>();
+
+    // Streak and today's progress towards the daily goal
+    let today_status = move || {
+        let events = events_ctx.events.read();
+        let goal = settings_ctx.settings.read().daily_goal;
+        let today = clock::today();
+        (
+            streak(&events, today),
+            goal.progress(&events, today),
+            goal.is_met(&events, today),
+            goal,
+        )
+    };
+
     view! {
         <div class="home-container">
+            <A href="/stats" attr:class="settings-link stats-link" attr:aria-label="Statistics">"📊"</A>
+            <A href="/settings" attr:class="settings-link" attr:aria-label="Settings">"⚙️"</A>
             <header class="home-header">
                 <img src="/vamos-icon.png" alt="Vamos!" style="max-width: 300px; height: auto; margin-bottom: 1rem;" />
             </header>
 
+            {move || {
+                let (streak, progress, met, goal) = today_status();
+                let percent = (progress * 100 / goal.target.max(1)).min(100);
+                view! {
+                    <A href="/stats" attr:class="home-goal">
+                        <span class="home-streak">{format!("🔥 {} day{}", streak, if streak == 1 { "" } else { "s" })}</span>
+                        <span class="home-goal-text">
+                            {if met {
+                                "Goal met today ✓".to_string()
+                            } else {
+                                format!("{} / {} {} today", progress, goal.target, goal.unit.name())
+                            }}
+                        </span>
+                        <span class="goal-bar">
+                            <span class="goal-bar-fill" style=format!("width: {}%;", percent)></span>
+                        </span>
+                    </A>
+                }
+            }}
+
             <div class="button-container">
                 <A href="/vocabulary" attr:class="nav-button">
                     <div class="button-icon">"📚"</div>
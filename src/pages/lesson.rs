@@ -0,0 +1,43 @@
+use crate::components::{DataErrorMessage, LessonContent};
+use crate::core::language::UI_LANGUAGE;
+use crate::data::{LearningDirection, load_grammar_lesson};
+use leptos::prelude::*;
+use leptos_router::{components::A, hooks::use_params_map};
+
+/// Lesson page - Shows one grammar lesson
+#[component]
+pub fn Lesson() -> impl IntoView {
+    let params = use_params_map();
+
+    // Extract lesson from URL params
+    let lesson = move || params.read().get("lesson").unwrap_or_default();
+
+    let document = Memo::new(move |_| load_grammar_lesson(&lesson()));
+
+    view! {
+        <div class="page-container">
+            <header class="page-header">
+                <A href="/grammar/lessons" attr:class="back-button">"❮"</A>
+                <h1>
+                    {move || document.read().as_ref().map(|d| d.title.get(UI_LANGUAGE).to_string()).unwrap_or_default()}
+                </h1>
+            </header>
+
+            <div class="lesson-container">
+                {move || match document.get() {
+                    Err(e) => view! {
+                        <DataErrorMessage error=e direction=LearningDirection::SpanishToEnglish/>
+                    }.into_any(),
+                    Ok(document) => view! {
+                        <LessonContent blocks=document.blocks/>
+                        {document.practice.map(|topic| view! {
+                            <A href=format!("/grammar/exercises/{}", topic) attr:class="nav-btn error-action">
+                                "Practice it →"
+                            </A>
+                        })}
+                    }.into_any(),
+                }}
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,41 @@
+use crate::components::DataErrorMessage;
+use crate::core::language::UI_LANGUAGE;
+use crate::data::{GRAMMAR_LESSONS, LearningDirection, load_grammar_lesson};
+use leptos::prelude::*;
+use leptos_router::components::A;
+
+/// Lesson index page - Lists the grammar lessons
+#[component]
+pub fn LessonIndex() -> impl IntoView {
+    let lessons = GRAMMAR_LESSONS
+        .iter()
+        .map(|lesson| match load_grammar_lesson(lesson) {
+            Ok(document) => view! {
+                <A href=format!("/grammar/lessons/{}", document.lesson) attr:class="grammar-topic">
+                    <span class="grammar-topic-icon">{document.emoji.clone()}</span>
+                    <span class="grammar-topic-title">
+                        {document.title.get(UI_LANGUAGE).to_string()}
+                        <span class="stage-level">{document.cefr.as_str()}</span>
+                    </span>
+                    <span class="grammar-topic-summary">{document.summary.get(UI_LANGUAGE).to_string()}</span>
+                </A>
+            }
+            .into_any(),
+            Err(e) => view! {
+                <DataErrorMessage error=e direction=LearningDirection::SpanishToEnglish/>
+            }
+            .into_any(),
+        })
+        .collect::<Vec<_>>();
+
+    view! {
+        <div class="page-container">
+            <header class="page-header">
+                <A href="/grammar" attr:class="back-button">"❮"</A>
+                <h1>"Lessons"</h1>
+            </header>
+
+            <div class="grammar-list">{lessons}</div>
+        </div>
+    }
+}
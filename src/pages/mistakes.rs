@@ -0,0 +1,178 @@
+use crate::components::{DataErrorMessage, VocabularyCard};
+use crate::core::events::EventKind;
+use crate::core::keymap::{KeyBinding, use_key_bindings};
+use crate::core::mastery::card_direction;
+use crate::core::mistakes::CORRECT_TO_CLEAR;
+use crate::core::{
+    EventLogContext, FavoritesContext, MasteryContext, MistakesContext, SettingsContext,
+};
+use crate::data::{DataError, DirectionMode, card_index_for_id, get_card_pair};
+use leptos::prelude::*;
+use leptos_router::components::A;
+use leptos_router::hooks::use_query_map;
+
+/// Mistakes page - Reviews the cards missed in quizzes until they are known
+#[component]
+pub fn Mistakes() -> impl IntoView {
+    let mistakes_ctx = expect_context::<MistakesContext>();
+    let favorites_ctx = expect_context::<FavoritesContext>();
+    let mastery_ctx = expect_context::<MasteryContext>();
+    let events_ctx = expect_context::<EventLogContext>();
+    let settings_ctx = expect_context::<SettingsContext>();
+    let query = use_query_map();
+
+    // Extract direction mode from query params
+    let mode = move || DirectionMode::from_query(query.read().get("dir").as_deref());
+
+    // State management
+    let (card_index, set_card_index) = signal(0usize);
+
+    let deck_cards = move || mistakes_ctx.deck.read().cards();
+
+    // Global ID of the card shown
+    let shown_card_id = move || {
+        let cards = deck_cards();
+        cards
+            .get(card_index.get())
+            .or(cards.last())
+            .map(|(_, card_id)| *card_id)
+    };
+
+    // Direction of the card shown; in mixed sessions it is picked once per
+    // card so it stays put while the card is shown
+    let direction = Memo::new(move |_| {
+        card_direction(
+            mode(),
+            settings_ctx.settings.read_untracked().mixed_order,
+            card_index.get(),
+            shown_card_id(),
+            &mastery_ctx.mastery.read_untracked(),
+        )
+    });
+    let direction = move || direction.get();
+
+    // Get current card of the deck, or None when the deck is empty
+    let current_card = move || {
+        let cards = deck_cards();
+        let (stage, card_id) = cards.get(card_index.get()).or(cards.last()).copied()?;
+        let card = card_index_for_id(card_id)
+            .ok_or(DataError::UnknownStage { stage })
+            .and_then(|card_idx| get_card_pair(stage, card_idx, direction()))
+            .map(|(source, target)| (stage, source, target));
+        Some(card)
+    };
+
+    // Navigation handlers
+    let go_next = move || {
+        if card_index.get() + 1 < deck_cards().len() {
+            set_card_index.update(|i| *i += 1);
+        }
+    };
+
+    let go_prev = move || {
+        if card_index.get() > 0 {
+            set_card_index.update(|i| *i -= 1);
+        }
+    };
+
+    use_key_bindings(vec![
+        KeyBinding::new(&["ArrowLeft"], "←", "Previous card", go_prev),
+        KeyBinding::new(&["ArrowRight"], "→", "Next card", go_next),
+    ]);
+
+    // Grade the card, then move on; past the end, go round the deck again
+    let grade = move |known: bool| {
+        let Some(Ok((_, source, _))) = current_card() else {
+            return;
+        };
+        // Read before the deck changes, which may move on to another card
+        let direction = direction();
+        let count_before = deck_cards().len();
+        mistakes_ctx.record(source.id, known);
+        mastery_ctx.record(source.id, direction, known);
+        events_ctx.record(source.id, EventKind::Grade { known });
+        let count = deck_cards().len();
+        let index = card_index.get_untracked();
+        // A cleared card leaves its place to the next one
+        let next = if count < count_before {
+            index
+        } else {
+            index + 1
+        };
+        set_card_index.set(if next < count { next } else { 0 });
+    };
+
+    let toggle_favorite = move || {
+        if let Some(Ok((stage, source, _))) = current_card() {
+            favorites_ctx.toggle(stage, source.id);
+        }
+    };
+
+    view! {
+        <div class="page-container">
+            <header class="page-header">
+                <A href={move || format!("/vocabulary?dir={}", mode().as_query())} attr:class="back-button">"❮"</A>
+                <h1>"Mistakes"</h1>
+            </header>
+
+            <div class="card-learning-container">
+                {move || {
+                    match current_card() {
+                        None => view! {
+                            <div class="error-message">
+                                <p>"No mistakes to review!"</p>
+                                <p style="color: #666; font-size: 1rem;">"Cards you miss in cloze, dictation, word order, gender or agreement quizzes collect here"</p>
+                            </div>
+                        }.into_any(),
+                        Some(Ok((stage, source, target))) => {
+                            let streak = mistakes_ctx.deck.read().streak(source.id).unwrap_or(0);
+                            view! {
+                                <div class="card-wrapper">
+                                    <VocabularyCard
+                                        source_word={source.word.clone()}
+                                        source_example={source.example.clone()}
+                                        target_word={target.word.clone()}
+                                        target_example={target.example.clone()}
+                                        card_index={card_index.get()}
+                                        card_count={deck_cards().len()}
+                                        is_favorite={favorites_ctx.is_favorite(stage, source.id)}
+                                        direction={direction()}
+                                        stage=stage
+                                        on_toggle_favorite=toggle_favorite
+                                        on_previous=Callback::new(move |_| go_prev())
+                                        on_next=Callback::new(move |_| go_next())
+                                        on_grade=Callback::new(grade)
+                                    />
+
+                                    <p class="mistake-streak">
+                                        {format!("{} / {} right in a row to clear this card", streak, CORRECT_TO_CLEAR)}
+                                    </p>
+
+                                    <div class="card-navigation">
+                                        <button
+                                            class="nav-btn"
+                                            on:click=move |_| go_prev()
+                                            disabled={move || card_index.get() == 0}
+                                        >
+                                            "← Previous"
+                                        </button>
+                                        <button
+                                            class="nav-btn"
+                                            on:click=move |_| go_next()
+                                            disabled={move || card_index.get() + 1 >= deck_cards().len()}
+                                        >
+                                            "Next →"
+                                        </button>
+                                    </div>
+                                </div>
+                            }.into_any()
+                        }
+                        Some(Err(e)) => view! {
+                            <DataErrorMessage error=e direction=direction()/>
+                        }.into_any()
+                    }
+                }}
+            </div>
+        </div>
+    }
+}
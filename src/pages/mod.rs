@@ -1,11 +1,43 @@
+pub mod accent_practice;
+pub mod agreement_practice;
+pub mod cloze;
+pub mod dictation;
 pub mod favorites;
+pub mod favorites_import;
+pub mod gender_practice;
 pub mod grammar;
+pub mod grammar_exercises;
 pub mod home;
+pub mod lesson;
+pub mod lesson_index;
+pub mod mistakes;
+pub mod numbers_practice;
+pub mod placement;
+pub mod scramble;
+pub mod search;
+pub mod settings;
+pub mod stats;
 pub mod vocabulary;
 pub mod vocabulary_cards;
 
+pub use accent_practice::AccentPractice;
+pub use agreement_practice::AgreementPractice;
+pub use cloze::ClozePractice;
+pub use dictation::DictationPractice;
 pub use favorites::Favorites;
+pub use favorites_import::FavoritesImport;
+pub use gender_practice::GenderPractice;
 pub use grammar::Grammar;
+pub use grammar_exercises::GrammarExercises;
 pub use home::Home;
+pub use lesson::Lesson;
+pub use lesson_index::LessonIndex;
+pub use mistakes::Mistakes;
+pub use numbers_practice::NumbersPractice;
+pub use placement::PlacementTest;
+pub use scramble::ScramblePractice;
+pub use search::Search;
+pub use settings::Settings;
+pub use stats::Stats;
 pub use vocabulary::Vocabulary;
 pub use vocabulary_cards::VocabularyCards;
@@ -0,0 +1,285 @@
+use crate::core::gender::Gender;
+use crate::core::keymap::{KeyBinding, use_key_bindings};
+use crate::core::numbers::DrillItem;
+use crate::core::random::random_below;
+use crate::core::speech;
+use crate::data::LearningDirection;
+use leptos::ev::SubmitEvent;
+use leptos::prelude::*;
+use leptos_router::components::A;
+use leptos_router::hooks::{use_navigate, use_query_map};
+
+/// Nouns counted in number questions: singular, plural and gender
+const COUNTED_NOUNS: [(&str, &str, Gender); 4] = [
+    ("euro", "euros", Gender::Masculine),
+    ("persona", "personas", Gender::Feminine),
+    ("año", "años", Gender::Masculine),
+    ("casa", "casas", Gender::Feminine),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum DrillKind {
+    Number,
+    Date,
+    Time,
+}
+
+impl DrillKind {
+    const ALL: [DrillKind; 3] = [DrillKind::Number, DrillKind::Date, DrillKind::Time];
+
+    fn label(&self) -> &'static str {
+        match self {
+            DrillKind::Number => "Numbers",
+            DrillKind::Date => "Dates",
+            DrillKind::Time => "Times",
+        }
+    }
+}
+
+fn random_item(kind: DrillKind) -> DrillItem {
+    match kind {
+        DrillKind::Number => {
+            // Pick the number of digits first so big numbers come up as often as small ones
+            let digits = 1 + random_below(10) as u32;
+            let noun = COUNTED_NOUNS.get(random_below(6) as usize).copied();
+            DrillItem::Number {
+                value: random_below(10u64.pow(digits)),
+                noun,
+            }
+        }
+        DrillKind::Date => {
+            let year = 1900 + random_below(201) as u32;
+            let month = 1 + random_below(12) as u32;
+            let leap =
+                year.is_multiple_of(4) && (!year.is_multiple_of(100) || year.is_multiple_of(400));
+            let days = match month {
+                2 if leap => 29,
+                2 => 28,
+                4 | 6 | 9 | 11 => 30,
+                _ => 31,
+            };
+            DrillItem::Date {
+                day: 1 + random_below(days) as u32,
+                month,
+                year,
+            }
+        }
+        DrillKind::Time => DrillItem::Time {
+            hour: random_below(24) as u32,
+            minute: 5 * random_below(12) as u32,
+        },
+    }
+}
+
+/// Numbers practice - Read, hear and say numbers, dates and times
+#[component]
+pub fn NumbersPractice() -> impl IntoView {
+    let query = use_query_map();
+    let navigate = use_navigate();
+
+    // Spanish to digits, or digits to Spanish
+    let direction = move || {
+        query
+            .read()
+            .get("dir")
+            .map(|d| {
+                if d == "en-to-es" {
+                    LearningDirection::EnglishToSpanish
+                } else {
+                    LearningDirection::SpanishToEnglish
+                }
+            })
+            .unwrap_or(LearningDirection::SpanishToEnglish)
+    };
+
+    let toggle_direction = move |_| {
+        let new_dir = match direction() {
+            LearningDirection::SpanishToEnglish => "en-to-es",
+            LearningDirection::EnglishToSpanish => "es-to-en",
+        };
+        navigate(
+            &format!("/grammar/numbers?dir={}", new_dir),
+            Default::default(),
+        );
+    };
+
+    // State management
+    let (kind, set_kind) = signal(DrillKind::Number);
+    let item = RwSignal::new(random_item(DrillKind::Number));
+    let (fill, set_fill) = signal(String::new());
+    let (graded, set_graded) = signal(None::<bool>);
+    let (show_text, set_show_text) = signal(false);
+    let (listening, set_listening) = signal(false);
+    let (score, set_score) = signal((0usize, 0usize));
+
+    let say = move || speech::speak(&item.read_untracked().phrases()[0], "es-ES");
+
+    let new_item = move || {
+        item.set(random_item(kind.get_untracked()));
+        set_fill.set(String::new());
+        set_graded.set(None);
+        set_show_text.set(false);
+    };
+
+    // Read the Spanish aloud when it is the question
+    Effect::new(move |_| {
+        item.track();
+        if direction() == LearningDirection::SpanishToEnglish {
+            say();
+        }
+    });
+
+    let grade = move |answer: String, dictated: bool| {
+        if graded.get_untracked().is_some() {
+            return;
+        }
+        let correct = match direction() {
+            LearningDirection::SpanishToEnglish => item.read_untracked().check_digits(&answer),
+            LearningDirection::EnglishToSpanish => {
+                item.read_untracked().check_words(&answer, dictated)
+            }
+        };
+        set_score.update(|(right, total)| {
+            *total += 1;
+            if correct {
+                *right += 1;
+            }
+        });
+        set_fill.set(answer);
+        set_graded.set(Some(correct));
+        if direction() == LearningDirection::EnglishToSpanish {
+            say();
+        }
+    };
+
+    let dictate = move |_| {
+        set_listening.set(true);
+        speech::listen("es-ES", move |heard| {
+            set_listening.set(false);
+            if let Some(heard) = heard {
+                grade(heard, true);
+            }
+        });
+    };
+
+    let go_next = move || {
+        if graded.get_untracked().is_some() {
+            new_item();
+        }
+    };
+
+    use_key_bindings(vec![
+        KeyBinding::new(&["s", "S"], "S", "Say it in Spanish", say),
+        KeyBinding::new(&["ArrowRight"], "→", "Next", go_next),
+    ]);
+
+    view! {
+        <div class="page-container">
+            <header class="page-header">
+                <A href="/grammar" attr:class="back-button">"❮"</A>
+                <h1>"Numbers"</h1>
+                <button class="direction-toggle" on:click=toggle_direction>
+                    {move || match direction() {
+                        LearningDirection::SpanishToEnglish => "🇪🇸 → 123",
+                        LearningDirection::EnglishToSpanish => "123 → 🇪🇸",
+                    }}
+                </button>
+            </header>
+
+            <div class="cloze-container">
+                <div class="drill-tabs">
+                    {DrillKind::ALL.into_iter().map(|k| view! {
+                        <button
+                            class=move || if kind.get() == k { "drill-tab active" } else { "drill-tab" }
+                            on:click=move |_| {
+                                set_kind.set(k);
+                                new_item();
+                            }
+                        >
+                            {k.label()}
+                        </button>
+                    }).collect::<Vec<_>>()}
+                </div>
+
+                <p class="cloze-progress">
+                    {move || {
+                        let (right, total) = score.get();
+                        format!("✓ {} / {}", right, total)
+                    }}
+                </p>
+
+                {move || match direction() {
+                    LearningDirection::SpanishToEnglish => view! {
+                        <div class="drill-question">
+                            <button class="audio-button" on:click=move |_| say()>"🔊"</button>
+                            {move || if show_text.get() || graded.get().is_some() {
+                                view! { <p class="cloze-sentence">{item.read().phrases()[0].clone()}</p> }.into_any()
+                            } else {
+                                view! {
+                                    <button class="reveal-button" on:click=move |_| set_show_text.set(true)>
+                                        "Show text"
+                                    </button>
+                                }.into_any()
+                            }}
+                        </div>
+                    }.into_any(),
+                    LearningDirection::EnglishToSpanish => view! {
+                        <p class="drill-digits">{move || item.read().digits()}</p>
+                    }.into_any(),
+                }}
+
+                <form
+                    class="cloze-form"
+                    on:submit=move |ev: SubmitEvent| {
+                        ev.prevent_default();
+                        if !fill.get_untracked().trim().is_empty() {
+                            grade(fill.get_untracked(), false);
+                        }
+                    }
+                >
+                    <input
+                        class="cloze-input"
+                        type="text"
+                        placeholder=move || match direction() {
+                            LearningDirection::SpanishToEnglish => "Write it in digits",
+                            LearningDirection::EnglishToSpanish => "Write it in Spanish",
+                        }
+                        autocomplete="off"
+                        autocapitalize="off"
+                        prop:value=fill
+                        prop:disabled=move || graded.get().is_some()
+                        on:input=move |ev| set_fill.set(event_target_value(&ev))
+                    />
+                    {move || (direction() == LearningDirection::EnglishToSpanish && speech::can_listen()).then(|| view! {
+                        <button
+                            class="nav-btn"
+                            type="button"
+                            title="Say it"
+                            disabled=move || graded.get().is_some() || listening.get()
+                            on:click=dictate
+                        >
+                            {move || if listening.get() { "…" } else { "🎤" }}
+                        </button>
+                    })}
+                    <button class="nav-btn" type="submit" disabled=move || graded.get().is_some()>
+                        "Check"
+                    </button>
+                </form>
+
+                {move || graded.get().map(|correct| {
+                    let phrases = item.read().phrases();
+                    view! {
+                        <div class="cloze-feedback">
+                            <p class=if correct { "cloze-result correct" } else { "cloze-result incorrect" }>
+                                {if correct { "✓ " } else { "✗ " }}
+                                {item.read().digits()}
+                            </p>
+                            <p class="accent-explanation">{phrases.join(" · ")}</p>
+                            <button class="nav-btn" on:click=move |_| go_next()>"Next →"</button>
+                        </div>
+                    }
+                })}
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,204 @@
+use crate::components::DataErrorMessage;
+use crate::core::MasteryContext;
+use crate::core::keymap::{KeyBinding, use_key_bindings};
+use crate::core::language::UI_LANGUAGE;
+use crate::core::placement::{
+    Placement, PlacementQuestion, QUESTIONS_PER_STAGE, placement_question,
+};
+use crate::core::random::random_below;
+use crate::data::{DataError, LearningDirection, load_stage_meta, load_vocabulary_stage};
+use leptos::prelude::*;
+use leptos_router::components::A;
+
+/// A question about a word of `stage` not asked yet in this test
+fn new_question(stage: u32, asked: &[u32]) -> Result<PlacementQuestion, DataError> {
+    let spanish = load_vocabulary_stage(stage, "es")?;
+    let english = load_vocabulary_stage(stage, "en")?;
+    let fresh: Vec<usize> = (0..spanish.len())
+        .filter(|i| !asked.contains(&spanish[*i].id))
+        .collect();
+    let index = fresh
+        .get(random_below(fresh.len() as u64) as usize)
+        .copied()
+        .unwrap_or(0);
+    placement_question(&spanish, &english, index).ok_or(DataError::UnknownStage { stage })
+}
+
+/// Placement test page - Finds the stage to start at from a few questions
+#[component]
+pub fn PlacementTest() -> impl IntoView {
+    let mastery_ctx = expect_context::<MasteryContext>();
+
+    // State management
+    let placement = RwSignal::new(Placement::default());
+    let asked = StoredValue::new(Vec::<u32>::new());
+    let (question, set_question) = signal(None::<Result<PlacementQuestion, DataError>>);
+    let (picked, set_picked) = signal(None::<String>);
+    let (marked, set_marked) = signal(None::<usize>);
+
+    // Ask about the stage being probed, until the test is over
+    let ask_next = move || {
+        set_picked.set(None);
+        let next = placement.read_untracked().probe().map(|stage| {
+            let question = new_question(stage, &asked.get_value());
+            if let Ok(q) = &question {
+                asked.update_value(|a| a.push(q.card_id));
+            }
+            question
+        });
+        set_question.set(next);
+    };
+    ask_next();
+
+    let pick = move |choice: String| {
+        if picked.get_untracked().is_some() {
+            return;
+        }
+        if let Some(Ok(q)) = question.get_untracked() {
+            placement.update(|p| p.answer(choice == q.answer));
+            set_picked.set(Some(choice));
+        }
+    };
+
+    let go_next = move || {
+        if picked.get_untracked().is_some() {
+            ask_next();
+        }
+    };
+
+    let restart = move |_| {
+        placement.set(Placement::default());
+        asked.set_value(Vec::new());
+        set_marked.set(None);
+        ask_next();
+    };
+
+    // Master every card of the stages before the recommended one
+    let mark_earlier_known = move |_| {
+        let Some(stage) = placement.read_untracked().recommended() else {
+            return;
+        };
+        let ids: Vec<u32> = (1..stage)
+            .flat_map(|s| load_vocabulary_stage(s, "es").unwrap_or_default())
+            .map(|card| card.id)
+            .collect();
+        set_marked.set(Some(ids.len()));
+        mastery_ctx.mark_known(ids);
+    };
+
+    let choose = move |n: usize| {
+        move || {
+            if let Some(Ok(q)) = question.get_untracked()
+                && let Some(choice) = q.choices.get(n)
+            {
+                pick(choice.clone());
+            }
+        }
+    };
+    use_key_bindings(vec![
+        KeyBinding::new(&["1"], "1", "Choose the first answer", choose(0)),
+        KeyBinding::new(&["2"], "2", "Choose the second answer", choose(1)),
+        KeyBinding::new(&["3"], "3", "Choose the third answer", choose(2)),
+        KeyBinding::new(&["4"], "4", "Choose the fourth answer", choose(3)),
+        KeyBinding::new(&["ArrowRight", "Enter"], "→", "Next word", go_next),
+    ]);
+
+    let stage_title = |stage: u32| {
+        load_stage_meta(stage)
+            .map(|m| format!("{} Stage {} · {}", m.emoji, stage, m.title.get(UI_LANGUAGE)))
+            .unwrap_or_else(|_| format!("Stage {}", stage))
+    };
+
+    view! {
+        <div class="page-container">
+            <header class="page-header">
+                <A href="/vocabulary" attr:class="back-button">"❮"</A>
+                <h1>"Placement test"</h1>
+            </header>
+
+            <div class="cloze-container">
+                {move || match question.get() {
+                    Some(Ok(q)) => {
+                        let answer = q.answer.clone();
+                        let buttons = q.choices.into_iter().map(|choice| {
+                            let is_answer = choice == answer;
+                            let label = choice.clone();
+                            let state = {
+                                let choice = choice.clone();
+                                move || match picked.get() {
+                                    Some(_) if is_answer => "cloze-choice correct",
+                                    Some(p) if p == choice => "cloze-choice incorrect",
+                                    _ => "cloze-choice",
+                                }
+                            };
+                            view! {
+                                <button
+                                    class=state
+                                    disabled=move || picked.get().is_some()
+                                    on:click=move |_| pick(choice.clone())
+                                >
+                                    {label}
+                                </button>
+                            }
+                        }).collect::<Vec<_>>();
+                        view! {
+                            <p class="cloze-progress">
+                                {move || {
+                                    let p = placement.read();
+                                    format!(
+                                        "Stage check {} · word {} of up to {}",
+                                        p.results.len() + 1,
+                                        p.answered() + 1,
+                                        QUESTIONS_PER_STAGE
+                                    )
+                                }}
+                            </p>
+                            <p class="cloze-sentence placement-word">{q.word}</p>
+                            <p class="accent-explanation">"What does it mean?"</p>
+                            <div class="cloze-choices">{buttons}</div>
+                            {move || picked.get().is_some().then(|| view! {
+                                <button class="nav-btn" on:click=move |_| go_next()>"Next →"</button>
+                            })}
+                        }.into_any()
+                    }
+                    Some(Err(e)) => view! {
+                        <DataErrorMessage error=e direction=LearningDirection::SpanishToEnglish/>
+                    }.into_any(),
+                    None => {
+                        let p = placement.get();
+                        let stage = p.recommended().unwrap_or(1);
+                        view! {
+                            <div class="cloze-summary">
+                                <p>"We recommend starting at"</p>
+                                <p class="cloze-score">{stage_title(stage)}</p>
+                                <A href=format!("/vocabulary/{}", stage) attr:class="nav-btn">
+                                    {format!("Start stage {} →", stage)}
+                                </A>
+                                {(stage > 1).then(|| view! {
+                                    {move || match marked.get() {
+                                        Some(count) => view! {
+                                            <p class="cloze-result correct">
+                                                {format!("✓ {} cards of stages 1–{} marked as known", count, stage - 1)}
+                                            </p>
+                                        }.into_any(),
+                                        None => view! {
+                                            <button class="nav-btn" on:click=mark_earlier_known>
+                                                {format!("Mark stages 1–{} as known", stage - 1)}
+                                            </button>
+                                        }.into_any(),
+                                    }}
+                                })}
+                                <button class="nav-btn" on:click=restart>"Take the test again"</button>
+                            </div>
+                            <ul class="placement-results">
+                                {p.results.iter().map(|(stage, passed)| view! {
+                                    <li>{format!("{} {}", if *passed { "✓" } else { "✗" }, stage_title(*stage))}</li>
+                                }).collect::<Vec<_>>()}
+                            </ul>
+                        }.into_any()
+                    }
+                }}
+            </div>
+        </div>
+    }
+}
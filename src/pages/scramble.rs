@@ -0,0 +1,230 @@
+use crate::components::DataErrorMessage;
+use crate::core::events::EventKind;
+use crate::core::keymap::{KeyBinding, use_key_bindings};
+use crate::core::language::Language;
+use crate::core::scramble::{Scramble, generate_scramble};
+use crate::core::{EventLogContext, MistakesContext};
+use crate::data::{DirectionMode, LearningDirection, load_vocabulary_stage};
+use leptos::prelude::*;
+use leptos_router::{components::A, hooks::use_params_map, hooks::use_query_map};
+
+/// Scramble practice page - Put the words of each example back in order
+#[component]
+pub fn ScramblePractice() -> impl IntoView {
+    let params = use_params_map();
+    let query = use_query_map();
+    let events_ctx = expect_context::<EventLogContext>();
+    let mistakes_ctx = expect_context::<MistakesContext>();
+
+    // Extract stage from URL params
+    let stage = move || {
+        params
+            .read()
+            .get("stage")
+            .and_then(|s| s.parse::<u32>().ok())
+            .unwrap_or(1)
+    };
+
+    // Extract direction mode from query params; quizzes go one way even in
+    // mixed sessions, which keep their mode in the links back
+    let mode = move || DirectionMode::from_query(query.read().get("dir").as_deref());
+    let direction = move || mode().primary();
+
+    // Sentences of the stage long enough to scramble
+    let scrambles = Memo::new(move |_| {
+        let spanish = load_vocabulary_stage(stage(), "es")?;
+        let english = load_vocabulary_stage(stage(), "en")?;
+        let direction = direction();
+        Ok(spanish
+            .iter()
+            .zip(&english)
+            .filter_map(|(es, en)| match direction {
+                LearningDirection::SpanishToEnglish => generate_scramble(en, es),
+                LearningDirection::EnglishToSpanish => generate_scramble(es, en),
+            })
+            .collect::<Vec<Scramble>>())
+    });
+
+    // State management
+    let (index, set_index) = signal(0usize);
+    // Chips placed so far, as indices into the scramble's chips
+    let placed = RwSignal::new(Vec::<usize>::new());
+    let (graded, set_graded) = signal(None::<bool>);
+    let (score, set_score) = signal(0usize);
+
+    let reset = move || {
+        placed.set(Vec::new());
+        set_graded.set(None);
+    };
+
+    // Restart when the stage or direction changes
+    Effect::new(move |_| {
+        scrambles.track();
+        set_index.set(0);
+        set_score.set(0);
+        reset();
+    });
+
+    let current = move || {
+        scrambles
+            .read()
+            .as_ref()
+            .ok()
+            .and_then(|s| s.get(index.get()).cloned())
+    };
+
+    let toggle_chip = move |chip: usize| {
+        if graded.get_untracked().is_some() {
+            return;
+        }
+        placed.update(|p| match p.iter().position(|&c| c == chip) {
+            Some(at) => {
+                p.remove(at);
+            }
+            None => p.push(chip),
+        });
+    };
+
+    let check = move || {
+        if graded.get_untracked().is_some() {
+            return;
+        }
+        if let Some(scramble) = current() {
+            let order = placed.get_untracked();
+            if order.len() < scramble.chips.len() {
+                return;
+            }
+            let correct = scramble.check(&order);
+            events_ctx.record(scramble.card_id, EventKind::Answer { correct });
+            mistakes_ctx.record(scramble.card_id, correct);
+            if correct {
+                set_score.update(|s| *s += 1);
+            }
+            set_graded.set(Some(correct));
+        }
+    };
+
+    let go_next = move || {
+        if graded.get_untracked().is_some() {
+            set_index.update(|i| *i += 1);
+            reset();
+        }
+    };
+
+    let restart = move |_| {
+        set_index.set(0);
+        set_score.set(0);
+        reset();
+    };
+
+    use_key_bindings(vec![
+        KeyBinding::new(&["Enter"], "Enter", "Check the sentence", check),
+        KeyBinding::new(
+            &["Backspace"],
+            "⌫",
+            "Take back the last word",
+            move || {
+                if let Some(&last) = placed.read_untracked().last() {
+                    toggle_chip(last);
+                }
+            },
+        ),
+        KeyBinding::new(&["ArrowRight"], "→", "Next sentence", go_next),
+    ]);
+
+    let hint_flag = move || match direction().target_language() {
+        Language::Spanish => "🇬🇧",
+        Language::English => "🇪🇸",
+    };
+
+    view! {
+        <div class="page-container">
+            <header class="page-header">
+                <A href={move || format!("/vocabulary/{}?dir={}", stage(), mode().as_query())} attr:class="back-button">"❮"</A>
+                <h1>"Word order"</h1>
+            </header>
+
+            <div class="cloze-container">
+                {move || match scrambles.get() {
+                    Err(e) => view! {
+                        <DataErrorMessage error=e direction=direction()/>
+                    }.into_any(),
+                    Ok(all) => match current() {
+                        Some(scramble) => {
+                            let chips = scramble.chips.clone();
+                            let placed_chips = {
+                                let chips = chips.clone();
+                                move || placed.get().into_iter().map(|i| {
+                                    let word = chips[i].clone();
+                                    view! {
+                                        <button class="scramble-chip" on:click=move |_| toggle_chip(i)>{word}</button>
+                                    }
+                                }).collect::<Vec<_>>()
+                            };
+                            let pool = chips.iter().cloned().enumerate().map(|(i, word)| view! {
+                                <button
+                                    class="scramble-chip"
+                                    class:used=move || placed.read().contains(&i)
+                                    disabled=move || placed.read().contains(&i) || graded.get().is_some()
+                                    on:click=move |_| toggle_chip(i)
+                                >
+                                    {word}
+                                </button>
+                            }).collect::<Vec<_>>();
+                            let chip_count = chips.len();
+                            view! {
+                                <p class="cloze-progress">
+                                    {format!("{} / {} · ✓ {}", index.get() + 1, all.len(), score.get())}
+                                </p>
+                                <p class="cloze-hint">{hint_flag()} " " {scramble.prompt.clone()}</p>
+
+                                <div class=move || match graded.get() {
+                                    Some(true) => "scramble-answer correct",
+                                    Some(false) => "scramble-answer incorrect",
+                                    None => "scramble-answer",
+                                }>
+                                    {placed_chips}
+                                </div>
+                                <div class="scramble-pool">{pool}</div>
+
+                                <button
+                                    class="nav-btn"
+                                    disabled=move || graded.get().is_some() || placed.read().len() < chip_count
+                                    on:click=move |_| check()
+                                >
+                                    "Check"
+                                </button>
+
+                                {move || graded.get().map(|correct| view! {
+                                    <div class="cloze-feedback">
+                                        {if correct {
+                                            view! { <p class="cloze-result correct">"✓ " {scramble.sentence.clone()}</p> }.into_any()
+                                        } else {
+                                            view! { <p class="cloze-result incorrect">"✗ " {scramble.sentence.clone()}</p> }.into_any()
+                                        }}
+                                        <button class="nav-btn" on:click=move |_| go_next()>"Next →"</button>
+                                    </div>
+                                })}
+                            }.into_any()
+                        }
+                        None if all.is_empty() => view! {
+                            <div class="error-message">
+                                <p>"No sentences to practice in this stage"</p>
+                            </div>
+                        }.into_any(),
+                        None => view! {
+                            <div class="cloze-summary">
+                                <p class="cloze-score">{format!("{} / {}", score.get(), all.len())}</p>
+                                <p>"sentences put back in order"</p>
+                                <button class="nav-btn" on:click=restart>"Practice again"</button>
+                                <A href={format!("/vocabulary/{}?dir={}", stage(), mode().as_query())} attr:class="error-link">
+                                    "Back to the cards"
+                                </A>
+                            </div>
+                        }.into_any(),
+                    },
+                }}
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,78 @@
+use crate::components::DataErrorMessage;
+use crate::core::search::{MatchedField, search_index};
+use crate::data::DirectionMode;
+use leptos::prelude::*;
+use leptos_router::components::A;
+use leptos_router::hooks::use_query_map;
+
+/// Maximum number of results listed for a query
+const MAX_RESULTS: usize = 50;
+
+/// Search page - Finds words and examples across all stages
+#[component]
+pub fn Search() -> impl IntoView {
+    let query = use_query_map();
+    let (search_text, set_search_text) = signal(String::new());
+
+    // Keep the direction mode for links back into the stages
+    let mode = move || DirectionMode::from_query(query.read().get("dir").as_deref());
+
+    let results = move || search_index().map(|index| index.search(&search_text.get(), MAX_RESULTS));
+
+    view! {
+        <div class="page-container">
+            <header class="page-header">
+                <A href={move || format!("/vocabulary?dir={}", mode().as_query())} attr:class="back-button">"❮"</A>
+                <h1>"Search"</h1>
+            </header>
+
+            <div class="vocab-content">
+                <input
+                    class="search-input"
+                    type="search"
+                    placeholder="Search words and examples"
+                    autofocus
+                    prop:value=search_text
+                    on:input=move |ev| set_search_text.set(event_target_value(&ev))
+                />
+
+                {move || match results() {
+                    Ok(results) if results.is_empty() && !search_text.get().trim().is_empty() => view! {
+                        <p class="search-empty">"No matching cards"</p>
+                    }.into_any(),
+                    Ok(results) => view! {
+                        <ul class="search-results">
+                            {results.into_iter().map(|result| {
+                                let href = format!(
+                                    "/vocabulary/{}?dir={}&card={}",
+                                    result.stage,
+                                    mode().as_query(),
+                                    result.card_id
+                                );
+                                let example = result.example.map(|example| {
+                                    let lang = if result.matched_field == MatchedField::SpanishExample { "🇪🇸" } else { "🇬🇧" };
+                                    view! { <p class="search-example">{lang} " " {example}</p> }
+                                });
+                                view! {
+                                    <li>
+                                        <A href=href attr:class="search-result">
+                                            <div class="search-result-words">
+                                                <span class="search-word">{result.spanish_word}</span>
+                                                <span class="search-translation">{result.english_word}</span>
+                                            </div>
+                                            <span class="search-stage">{format!("Stage {}", result.stage)}</span>
+                                        </A>
+                                        {example}
+                                    </li>
+                                }
+                            }).collect::<Vec<_>>()}
+                        </ul>
+                    }.into_any(),
+                    Err(e) => view! {
+                        <DataErrorMessage error=e.clone() direction=mode().primary()/>
+                    }.into_any(),
+                }}
+            </div>
+        </div>
+    }
+}
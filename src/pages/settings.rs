@@ -0,0 +1,186 @@
+use crate::core::SettingsContext;
+use crate::core::goals::GoalUnit;
+use crate::core::ipa::{Dialect, transcribe_text};
+use crate::core::mastery::MixedOrder;
+use crate::core::reminders;
+use leptos::prelude::*;
+use leptos_router::components::A;
+
+/// Sentence showing how the dialects differ
+const PREVIEW: &str = "Yo llevo cerveza a la gente";
+
+/// Hour reminders are first set to, in the evening
+const DEFAULT_REMINDER_HOUR: u32 = 19;
+
+/// Settings page - Pronunciation preferences
+#[component]
+pub fn Settings() -> impl IntoView {
+    let settings = expect_context::<SettingsContext>();
+
+    let dialect = move || settings.settings.read().dialect;
+    let show_ipa = move || settings.settings.read().show_ipa;
+    let mixed_order = move || settings.settings.read().mixed_order;
+    let daily_goal = move || settings.settings.read().daily_goal;
+    let reminder_hour = move || settings.settings.read().reminder_hour;
+    let (notifications_denied, set_notifications_denied) = signal(false);
+
+    let on_dialect = move |ev| {
+        let index = event_target_value(&ev).parse::<usize>().unwrap_or(0);
+        if let Some(dialect) = Dialect::ALL.get(index).copied() {
+            settings.update(|s| s.dialect = dialect);
+        }
+    };
+
+    let on_mixed_order = move |ev| {
+        let index = event_target_value(&ev).parse::<usize>().unwrap_or(0);
+        if let Some(order) = MixedOrder::ALL.get(index).copied() {
+            settings.update(|s| s.mixed_order = order);
+        }
+    };
+
+    let on_goal_unit = move |ev| {
+        let index = event_target_value(&ev).parse::<usize>().unwrap_or(0);
+        if let Some(unit) = GoalUnit::ALL.get(index).copied() {
+            settings.update(|s| s.daily_goal.unit = unit);
+        }
+    };
+
+    let on_goal_target = move |ev| {
+        if let Ok(target) = event_target_value(&ev).parse::<u32>()
+            && target > 0
+        {
+            settings.update(|s| s.daily_goal.target = target);
+        }
+    };
+
+    // Reminders need permission to show notifications first
+    let on_reminders = move |ev| {
+        if !event_target_checked(&ev) {
+            settings.update(|s| s.reminder_hour = None);
+            return;
+        }
+        reminders::request_permission(move |granted| {
+            set_notifications_denied.set(!granted);
+            // Written even when denied, to untick the checkbox again
+            settings.update(|s| s.reminder_hour = granted.then_some(DEFAULT_REMINDER_HOUR));
+        });
+    };
+
+    let on_reminder_hour = move |ev| {
+        if let Ok(hour) = event_target_value(&ev).parse::<u32>() {
+            settings.update(|s| s.reminder_hour = Some(hour));
+        }
+    };
+
+    view! {
+        <div class="page-container">
+            <header class="page-header">
+                <A href="/" attr:class="back-button">"❮"</A>
+                <h1>"Settings"</h1>
+            </header>
+
+            <div class="settings-content">
+                <section class="settings-section">
+                    <h2>"Pronunciation"</h2>
+                    <label class="settings-row">
+                        <input
+                            type="checkbox"
+                            prop:checked=show_ipa
+                            on:change=move |ev| {
+                                let checked = event_target_checked(&ev);
+                                settings.update(|s| s.show_ipa = checked);
+                            }
+                        />
+                        "Show IPA transcriptions on cards"
+                    </label>
+                    <label class="settings-row">
+                        "Accent"
+                        <select class="settings-select" on:change=on_dialect>
+                            {Dialect::ALL.iter().enumerate().map(|(index, d)| {
+                                let d = *d;
+                                view! {
+                                    <option value=index.to_string() selected=move || dialect() == d>
+                                        {d.name()}
+                                    </option>
+                                }
+                            }).collect::<Vec<_>>()}
+                        </select>
+                    </label>
+                    <p class="settings-preview">
+                        <span>{PREVIEW}</span>
+                        <span class="card-ipa">{move || format!("/{}/", transcribe_text(PREVIEW, dialect()))}</span>
+                    </p>
+                </section>
+
+                <section class="settings-section">
+                    <h2>"Study"</h2>
+                    <label class="settings-row">
+                        "Direction of each card in 🇪🇸 ⇄ 🇬🇧 sessions"
+                        <select class="settings-select" on:change=on_mixed_order>
+                            {MixedOrder::ALL.iter().enumerate().map(|(index, o)| {
+                                let o = *o;
+                                view! {
+                                    <option value=index.to_string() selected=move || mixed_order() == o>
+                                        {o.name()}
+                                    </option>
+                                }
+                            }).collect::<Vec<_>>()}
+                        </select>
+                    </label>
+                </section>
+
+                <section class="settings-section">
+                    <h2>"Daily goal"</h2>
+                    <label class="settings-row">
+                        "Study every day"
+                        <span class="settings-goal">
+                            <input
+                                type="number"
+                                class="settings-number"
+                                min="1"
+                                prop:value=move || daily_goal().target.to_string()
+                                on:change=on_goal_target
+                            />
+                            <select class="settings-select" on:change=on_goal_unit>
+                                {GoalUnit::ALL.iter().enumerate().map(|(index, u)| {
+                                    let u = *u;
+                                    view! {
+                                        <option value=index.to_string() selected=move || daily_goal().unit == u>
+                                            {u.name()}
+                                        </option>
+                                    }
+                                }).collect::<Vec<_>>()}
+                            </select>
+                        </span>
+                    </label>
+                    <label class="settings-row">
+                        <input
+                            type="checkbox"
+                            prop:checked=move || reminder_hour().is_some()
+                            on:change=on_reminders
+                        />
+                        "Remind me when the goal isn't met"
+                    </label>
+                    {move || reminder_hour().map(|hour| view! {
+                        <label class="settings-row">
+                            "Remind me at"
+                            <select class="settings-select" on:change=on_reminder_hour>
+                                {(0..24).map(|h| view! {
+                                    <option value=h.to_string() selected=h == hour>
+                                        {format!("{:02}:00", h)}
+                                    </option>
+                                }).collect::<Vec<_>>()}
+                            </select>
+                        </label>
+                        <p class="settings-hint">
+                            "With the app closed, reminders rely on background sync: they only arrive in Chrome or Edge with the app installed, and may come late"
+                        </p>
+                    })}
+                    {move || notifications_denied.get().then(|| view! {
+                        <p class="settings-note">"Notifications are blocked for this site, so reminders can't be shown"</p>
+                    })}
+                </section>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,151 @@
+use crate::components::{BarChart, CalendarHeatmap, LineChart};
+use crate::core::EventLogContext;
+use crate::core::clock::{self, civil_date, weekday};
+use crate::core::stats::{accuracy_by_stage, cards_per_day, learned_by_day, streak};
+use leptos::prelude::*;
+use leptos_router::components::A;
+
+/// Days of activity shown as bars
+const ACTIVITY_DAYS: i64 = 14;
+/// Days the learned cards are charted over
+const LEARNED_DAYS: i64 = 30;
+/// Weeks shown in the calendar
+const CALENDAR_WEEKS: i64 = 18;
+
+/// A day as "18/10"
+fn short_date(day: i64) -> String {
+    let (_, month, day_of_month) = civil_date(day);
+    format!("{}/{}", day_of_month, month)
+}
+
+/// Statistics page - Charts of the locally recorded study events
+#[component]
+pub fn Stats() -> impl IntoView {
+    let events_ctx = expect_context::<EventLogContext>();
+    let events = events_ctx.events;
+    let today = clock::today();
+
+    let activity = move || {
+        let first = today - ACTIVITY_DAYS + 1;
+        let labels = (first..=today)
+            .map(|day| {
+                let (_, _, day_of_month) = civil_date(day);
+                day_of_month.to_string()
+            })
+            .collect();
+        view! { <BarChart values=cards_per_day(&events.read(), first, today) labels/> }
+    };
+
+    let learned = move || {
+        let first = today - LEARNED_DAYS + 1;
+        let labels = (first..=today)
+            .map(|day| {
+                if day == first || day == today {
+                    short_date(day)
+                } else {
+                    String::new()
+                }
+            })
+            .collect();
+        view! { <LineChart values=learned_by_day(&events.read(), first, today) labels/> }
+    };
+
+    let accuracy = move || {
+        let stages = accuracy_by_stage(&events.read());
+        if stages.is_empty() {
+            return view! { <p class="stats-empty">"Answer quizzes or grade cards to see your accuracy"</p> }
+                .into_any();
+        }
+        let labels = stages.iter().map(|s| s.stage.to_string()).collect();
+        let values = stages.iter().map(|s| s.percent()).collect();
+        view! { <BarChart values labels max=100/> }.into_any()
+    };
+
+    let calendar = move || {
+        // Start on the Monday of the first week shown
+        let first = today - weekday(today) as i64 - (CALENDAR_WEEKS - 1) * 7;
+        view! { <CalendarHeatmap first_day=first counts=cards_per_day(&events.read(), first, today)/> }
+    };
+
+    let totals = move || {
+        let events = events.read();
+        let learned = learned_by_day(&events, today, today)
+            .first()
+            .copied()
+            .unwrap_or(0);
+        let (correct, answered) = accuracy_by_stage(&events)
+            .iter()
+            .fold((0, 0), |(c, t), s| (c + s.correct, t + s.total));
+        (
+            streak(&events, today),
+            cards_per_day(&events, today, today)[0],
+            learned,
+            (answered > 0).then(|| correct * 100 / answered),
+        )
+    };
+
+    view! {
+        <div class="page-container">
+            <header class="page-header">
+                <A href="/" attr:class="back-button">"❮"</A>
+                <h1>"Statistics"</h1>
+            </header>
+
+            {move || if events.read().is_empty() {
+                view! {
+                    <div class="error-message">
+                        <p>"No study recorded yet"</p>
+                        <p style="color: #666; font-size: 1rem;">"Statistics appear here as you study vocabulary cards"</p>
+                    </div>
+                }.into_any()
+            } else {
+                let (streak, today_cards, learned_cards, accuracy_percent) = totals();
+                view! {
+                    <div class="stats-container">
+                        <dl class="session-stats">
+                            <div>
+                                <dt>"Streak"</dt>
+                                <dd>{format!("🔥 {}", streak)}</dd>
+                            </div>
+                            <div>
+                                <dt>"Cards today"</dt>
+                                <dd>{today_cards}</dd>
+                            </div>
+                            <div>
+                                <dt>"Cards learned"</dt>
+                                <dd>{learned_cards}</dd>
+                            </div>
+                            <div>
+                                <dt>"Accuracy"</dt>
+                                <dd>{accuracy_percent.map_or("–".to_string(), |a| format!("{}%", a))}</dd>
+                            </div>
+                        </dl>
+
+                        <section class="stats-section">
+                            <h2>"Daily activity"</h2>
+                            <p class="stats-caption">"Cards studied each day, over the last two weeks"</p>
+                            {activity}
+                        </section>
+
+                        <section class="stats-section">
+                            <h2>"Cards learned"</h2>
+                            <p class="stats-caption">"Cards answered right or known at least once"</p>
+                            {learned}
+                        </section>
+
+                        <section class="stats-section">
+                            <h2>"Accuracy by stage"</h2>
+                            <p class="stats-caption">"Share of quiz answers and grades that were right, in %"</p>
+                            {accuracy}
+                        </section>
+
+                        <section class="stats-section">
+                            <h2>"Calendar"</h2>
+                            {calendar}
+                        </section>
+                    </div>
+                }.into_any()
+            }}
+        </div>
+    }
+}
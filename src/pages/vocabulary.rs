@@ -1,3 +1,6 @@
+use crate::core::MistakesContext;
+use crate::core::language::UI_LANGUAGE;
+use crate::data::{STAGE_COUNT, load_stage_meta};
 use leptos::prelude::*;
 use leptos_router::components::A;
 use leptos_router::hooks::{use_navigate, use_query_map};
@@ -7,22 +10,23 @@ use leptos_router::hooks::{use_navigate, use_query_map};
 pub fn Vocabulary() -> impl IntoView {
     let query = use_query_map();
     let navigate = use_navigate();
+    let mistakes_ctx = expect_context::<MistakesContext>();
 
     // State for learning direction - sync with URL query param
     let direction = Memo::new(move |_| {
         query
             .read()
             .get("dir")
-            .filter(|d| d == "en-to-es" || d == "es-to-en")
+            .filter(|d| d == "en-to-es" || d == "es-to-en" || d == "both")
             .unwrap_or("es-to-en".to_string())
     });
 
     // Toggle direction handler
     let toggle_direction = move |_| {
-        let new_dir = if direction.get() == "es-to-en" {
-            "en-to-es"
-        } else {
-            "es-to-en"
+        let new_dir = match direction.get().as_str() {
+            "es-to-en" => "en-to-es",
+            "en-to-es" => "both",
+            _ => "es-to-en",
         };
         // Update URL to persist direction in browser history
         navigate(&format!("/vocabulary?dir={}", new_dir), Default::default());
@@ -37,23 +41,34 @@ pub fn Vocabulary() -> impl IntoView {
                     class="direction-toggle"
                     on:click=toggle_direction
                 >
-                    {move || {
-                        if direction.get() == "es-to-en" {
-                            "🇪🇸 → 🇬🇧"
-                        } else {
-                            "🇬🇧 → 🇪🇸"
-                        }
+                    {move || match direction.get().as_str() {
+                        "es-to-en" => "🇪🇸 → 🇬🇧",
+                        "en-to-es" => "🇬🇧 → 🇪🇸",
+                        _ => "🇪🇸 ⇄ 🇬🇧",
                     }}
                 </button>
             </header>
 
             <div class="vocab-content">
+                <A href={move || format!("/search?dir={}", direction.get())} attr:class="search-link">
+                    "🔍 Search all stages"
+                </A>
+                <A href="/vocabulary/placement" attr:class="search-link">
+                    "🎯 Not sure where to start? Take the placement test"
+                </A>
+
                 <div class="stage-grid">
-                    {move || (1..=21).map(|stage| {
+                    {move || (1..=STAGE_COUNT).map(|stage| {
                         let href = format!("/vocabulary/{}?dir={}", stage, direction.get());
+                        let meta = load_stage_meta(stage).ok();
                         view! {
                             <A href=href attr:class="stage-button">
-                                {stage.to_string()}
+                                {meta.as_ref().map(|m| view! { <span class="stage-emoji">{m.emoji.clone()}</span> })}
+                                <span class="stage-number">{stage.to_string()}</span>
+                                {meta.map(|m| view! {
+                                    <span class="stage-title">{m.title.get(UI_LANGUAGE).to_string()}</span>
+                                    <span class="stage-level">{m.cefr.as_str()}</span>
+                                })}
                             </A>
                         }
                     }).collect::<Vec<_>>()}
@@ -61,6 +76,10 @@ pub fn Vocabulary() -> impl IntoView {
                     <A href={move || format!("/vocabulary/favorites?dir={}", direction.get())} attr:class="stage-button favorites-button">
                         "⭐"
                     </A>
+                    <A href={move || format!("/vocabulary/mistakes?dir={}", direction.get())} attr:class="stage-button mistakes-button" attr:title="Mistakes">
+                        <span>"❗"</span>
+                        <span class="mistakes-count">{move || mistakes_ctx.deck.read().len()}</span>
+                    </A>
                 </div>
             </div>
         </div>
@@ -1,6 +1,18 @@
-use crate::components::VocabularyCard;
-use crate::core::FavoritesContext;
-use crate::data::{LearningDirection, get_card_pair, get_stage_card_count};
+use crate::components::{DataErrorMessage, SessionSummary, VocabularyCard};
+use crate::core::clock;
+use crate::core::events::EventKind;
+use crate::core::keymap::{KeyBinding, use_key_bindings};
+use crate::core::language::UI_LANGUAGE;
+use crate::core::mastery::card_direction;
+use crate::core::random::shuffle;
+use crate::core::session::SessionStats;
+use crate::core::{
+    EventLogContext, FavoritesContext, MasteryContext, ProgressContext, SettingsContext,
+};
+use crate::data::{
+    DirectionMode, LearningDirection, STAGE_COUNT, card_index_for_id, get_card_pair,
+    get_stage_card_count, load_stage_meta, load_vocabulary_stage, stage_for_card_id,
+};
 use leptos::prelude::*;
 use leptos_router::{components::A, hooks::use_params_map, hooks::use_query_map};
 
@@ -10,6 +22,10 @@ pub fn VocabularyCards() -> impl IntoView {
     let params = use_params_map();
     let query = use_query_map();
     let favorites_ctx = expect_context::<FavoritesContext>();
+    let progress_ctx = expect_context::<ProgressContext>();
+    let mastery_ctx = expect_context::<MasteryContext>();
+    let settings_ctx = expect_context::<SettingsContext>();
+    let events_ctx = expect_context::<EventLogContext>();
 
     // Extract stage from URL params
     let stage = move || {
@@ -20,31 +36,90 @@ pub fn VocabularyCards() -> impl IntoView {
             .unwrap_or(1)
     };
 
-    // Extract direction from query params
-    let direction = move || {
+    let stage_meta = move || load_stage_meta(stage()).ok();
+
+    // Global IDs of the stage's cards, empty if the stage doesn't load
+    let card_ids = Memo::new(move |_| {
+        load_vocabulary_stage(stage(), "es")
+            .map(|cards| cards.iter().map(|c| c.id).collect::<Vec<_>>())
+            .unwrap_or_default()
+    });
+
+    // Extract direction mode from query params
+    let mode = move || DirectionMode::from_query(query.read().get("dir").as_deref());
+
+    // State management: the session goes through `order` (card indices
+    // within the stage), and `position` is how far along it is
+    let (order, set_order) = signal(Vec::<usize>::new());
+    let (position, set_position) = signal(0usize);
+    let (card_count, set_card_count) = signal(0usize);
+    let stats = RwSignal::new(SessionStats::new(clock::now_ms()));
+
+    let card_index = Memo::new(move |_| order.read().get(position.get()).copied().unwrap_or(0));
+    let session_length = move || order.read().len();
+    // A memo, so that updating the stats doesn't re-render the card
+    let finished = Memo::new(move |_| stats.read().is_finished());
+    let finished = move || finished.get();
+
+    // Direction of the current card; in mixed sessions it is picked once per
+    // card so it stays put while the card is shown
+    let direction = Memo::new(move |_| {
+        let mode = mode();
+        let card_id = match mode {
+            DirectionMode::Single(_) => None,
+            DirectionMode::Mixed => card_ids.read().get(card_index.get()).copied(),
+        };
+        card_direction(
+            mode,
+            settings_ctx.settings.read_untracked().mixed_order,
+            position.get(),
+            card_id,
+            &mastery_ctx.mastery.read_untracked(),
+        )
+    });
+    let direction = move || direction.get();
+
+    // Card linked from elsewhere (e.g. search), as a global card ID
+    let linked_card_index = move || {
         query
             .read()
-            .get("dir")
-            .map(|d| {
-                if d == "en-to-es" {
-                    LearningDirection::EnglishToSpanish
-                } else {
-                    LearningDirection::SpanishToEnglish
-                }
-            })
-            .unwrap_or(LearningDirection::SpanishToEnglish)
+            .get("card")
+            .and_then(|id| id.parse::<u32>().ok())
+            .filter(|id| stage_for_card_id(*id) == Some(stage()))
+            .and_then(card_index_for_id)
+            .unwrap_or(0)
     };
 
-    // State management
-    let (card_index, set_card_index) = signal(0usize);
-    let (card_count, set_card_count) = signal(0usize);
+    // Log a study event about the card at `index` of the stage
+    let record_event = move |index: usize, kind: EventKind| {
+        if let Some(card_id) = card_ids.read_untracked().get(index).copied() {
+            events_ctx.record(card_id, kind);
+        }
+    };
+
+    // Move to a position in the session, counting its card as seen
+    let show = move |to: usize| {
+        set_position.set(to);
+        if let Some(index) = order.read_untracked().get(to).copied() {
+            stats.update(|s| s.see(index));
+            record_event(index, EventKind::View);
+        }
+    };
+
+    // Start a new session over the given cards
+    let start_session = move |cards: Vec<usize>, start: usize| {
+        set_order.set(cards);
+        stats.set(SessionStats::new(clock::now_ms()));
+        show(start);
+    };
 
     // Initialize card count when stage changes
     Effect::new(move |_| {
         let current_stage = stage();
         if let Ok(count) = get_stage_card_count(current_stage) {
             set_card_count.set(count);
-            set_card_index.set(0);
+            start_session((0..count).collect(), linked_card_index());
+            progress_ctx.mark_studied(current_stage);
         }
     });
 
@@ -55,17 +130,83 @@ pub fn VocabularyCards() -> impl IntoView {
         get_card_pair(current_stage, index, direction())
     };
 
-    // Navigation handlers
-    let go_next = move |_| {
-        if card_index.get() < card_count.get() - 1 {
-            set_card_index.update(|i| *i += 1);
+    // Navigation handlers; going past the last card ends the session
+    let go_next = move || {
+        if finished() {
+            return;
+        }
+        if position.get() + 1 < session_length() {
+            show(position.get() + 1);
+        } else {
+            stats.update(|s| s.finish(clock::now_ms()));
+        }
+    };
+
+    let go_prev = move || {
+        if !finished() && position.get() > 0 {
+            show(position.get() - 1);
         }
     };
 
-    let go_prev = move |_| {
-        if card_index.get() > 0 {
-            set_card_index.update(|i| *i -= 1);
+    use_key_bindings(vec![
+        KeyBinding::new(&["ArrowLeft"], "←", "Previous card", go_prev),
+        KeyBinding::new(&["ArrowRight"], "→", "Next card", go_next),
+    ]);
+
+    // Record how well the card is known in the direction it was shown in
+    let grade = move |known: bool| {
+        if let Ok((source, _)) = current_card() {
+            mastery_ctx.record(source.id, direction(), known);
         }
+        let index = card_index.get_untracked();
+        stats.update(|s| s.grade(index, known));
+        record_event(index, EventKind::Grade { known });
+        go_next();
+    };
+
+    let reveal_translation = move |example_shown: bool| {
+        let index = card_index.get_untracked();
+        stats.update(|s| s.reveal(index, example_shown));
+        record_event(index, EventKind::Reveal);
+    };
+
+    let review_missed = move || {
+        let missed = stats.read_untracked().missed();
+        start_session(missed, 0);
+    };
+
+    let restart_shuffled = move || {
+        let mut cards: Vec<usize> = (0..card_count.get_untracked()).collect();
+        shuffle(&mut cards);
+        start_session(cards, 0);
+    };
+
+    // Word and translation of the cards missed in the session, in Spanish first
+    let missed_words = move || {
+        stats
+            .read()
+            .missed()
+            .into_iter()
+            .filter_map(|index| {
+                get_card_pair(stage(), index, LearningDirection::SpanishToEnglish).ok()
+            })
+            .map(|(spanish, english)| (spanish.word, english.word))
+            .collect::<Vec<_>>()
+    };
+
+    let next_stage_href = move || {
+        (stage() < STAGE_COUNT)
+            .then(|| format!("/vocabulary/{}?dir={}", stage() + 1, mode().as_query()))
+    };
+
+    // Cards of the stage mastered in each direction
+    let mastered = move || {
+        let ids = card_ids.read();
+        let mastery = mastery_ctx.mastery.read();
+        (
+            mastery.mastered_count(ids.iter().copied(), LearningDirection::SpanishToEnglish),
+            mastery.mastered_count(ids.iter().copied(), LearningDirection::EnglishToSpanish),
+        )
     };
 
     // Toggle favorite
@@ -73,6 +214,8 @@ pub fn VocabularyCards() -> impl IntoView {
         let current_stage = stage();
         if let Ok((source, _)) = current_card() {
             favorites_ctx.toggle(current_stage, source.id);
+            let added = favorites_ctx.is_favorite(current_stage, source.id);
+            stats.update(|s| s.favorite(source.id, added));
         }
     };
 
@@ -88,12 +231,59 @@ pub fn VocabularyCards() -> impl IntoView {
     view! {
         <div class="page-container">
             <header class="page-header">
-                <A href={move || format!("/vocabulary?dir={}", if direction() == LearningDirection::EnglishToSpanish { "en-to-es" } else { "es-to-en" })} attr:class="back-button">"❮"</A>
-                <h1>"Stage " {move || stage()}</h1>
+                <A href={move || format!("/vocabulary?dir={}", mode().as_query())} attr:class="back-button">"❮"</A>
+                <h1>{move || stage_meta().map(|m| m.title.get(UI_LANGUAGE).to_string()).unwrap_or_else(|| format!("Stage {}", stage()))}</h1>
+                <A href={move || format!("/vocabulary/{}/cloze?dir={}", stage(), mode().as_query())} attr:class="header-action">"✏️ Cloze"</A>
+                <A href={move || format!("/vocabulary/{}/dictation?dir={}", stage(), mode().as_query())} attr:class="header-action" attr:title="Dictation">"🎧"</A>
+                <A href={move || format!("/vocabulary/{}/scramble?dir={}", stage(), mode().as_query())} attr:class="header-action" attr:title="Word order">"🧩"</A>
             </header>
 
+            {move || stage_meta().map(|m| {
+                let prerequisites = m.prerequisites
+                    .iter()
+                    .filter_map(|p| load_stage_meta(*p).ok())
+                    .map(|p| p.title.get(UI_LANGUAGE).to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                view! {
+                    <div class="stage-intro">
+                        <p>
+                            <span class="stage-level">{m.cefr.as_str()}</span>
+                            {format!(" {} Stage {} · {}", m.emoji, m.stage, m.description.get(UI_LANGUAGE))}
+                        </p>
+                        {(!prerequisites.is_empty()).then(|| view! {
+                            <p class="stage-prerequisites">"Builds on: " {prerequisites}</p>
+                        })}
+                        <p class="direction-mastery">
+                            {move || {
+                                let (recognition, production) = mastered();
+                                format!(
+                                    "Mastered: 🇪🇸 → 🇬🇧 {} / {} · 🇬🇧 → 🇪🇸 {} / {}",
+                                    recognition,
+                                    card_count.get(),
+                                    production,
+                                    card_count.get()
+                                )
+                            }}
+                        </p>
+                    </div>
+                }
+            })}
+
             <div class="card-learning-container">
                 {move || {
+                    if finished() {
+                        return view! {
+                            <SessionSummary
+                                stats=stats.get()
+                                card_count=card_count.get()
+                                missed_words=missed_words()
+                                on_review_missed=Callback::new(move |_| review_missed())
+                                on_restart=Callback::new(move |_| restart_shuffled())
+                                next_stage_href=next_stage_href()
+                            />
+                        }.into_any();
+                    }
                     match current_card() {
                         Ok((source, target)) => {
                             view! {
@@ -103,37 +293,37 @@ pub fn VocabularyCards() -> impl IntoView {
                                         source_example={source.example.clone()}
                                         target_word={target.word.clone()}
                                         target_example={target.example.clone()}
-                                        card_index={card_index.get()}
-                                        card_count={card_count.get()}
+                                        card_index={position.get()}
+                                        card_count={session_length()}
                                         is_favorite={is_favorite()}
                                         direction={direction()}
                                         on_toggle_favorite=move || toggle_favorite(())
+                                        on_previous=Callback::new(move |_| go_prev())
+                                        on_next=Callback::new(move |_| go_next())
+                                        on_grade=Callback::new(grade)
+                                        on_reveal_translation=Callback::new(reveal_translation)
                                     />
 
                                     <div class="card-navigation">
                                         <button
                                             class="nav-btn"
-                                            on:click=go_prev
-                                            disabled={move || card_index.get() == 0}
+                                            on:click=move |_| go_prev()
+                                            disabled={move || position.get() == 0}
                                         >
                                             "← Previous"
                                         </button>
                                         <button
                                             class="nav-btn"
-                                            on:click=go_next
-                                            disabled={move || card_index.get() >= card_count.get() - 1}
+                                            on:click=move |_| go_next()
                                         >
-                                            "Next →"
+                                            {move || if position.get() + 1 < session_length() { "Next →" } else { "Finish ✓" }}
                                         </button>
                                     </div>
                                 </div>
                             }.into_any()
                         }
                         Err(e) => view! {
-                            <div class="error-message">
-                                <p>"Error loading cards: " {e}</p>
-                                <A href="/vocabulary" attr:class="back-button">"❮"</A>
-                            </div>
+                            <DataErrorMessage error=e direction=direction()/>
                         }.into_any()
                     }
                 }}
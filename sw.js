@@ -0,0 +1,159 @@
+// Service worker for offline support.
+// This file is a template: scripts/generate-sw.sh fills in the cache version
+// and precache list after every Trunk build.
+
+const CACHE_VERSION = "__CACHE_VERSION__";
+const CACHE_NAME = `vamos-${CACHE_VERSION}`;
+const PRECACHE_ASSETS = __PRECACHE_ASSETS__;
+
+self.addEventListener("install", (event) => {
+  event.waitUntil(
+    caches.open(CACHE_NAME).then((cache) => cache.addAll(PRECACHE_ASSETS)),
+  );
+});
+
+// Daily goal reminder scheduled by the page. It is kept in its own cache to
+// survive the worker stopping, and shown from a timer while the worker runs
+// or on the next periodic sync after it is due. Browsers stop idle workers
+// within minutes, so with the app closed only periodic sync (Chromium,
+// installed app) shows it. Once shown, it is set again for the next day.
+const REMINDER_CACHE = "vamos.reminder";
+const REMINDER_KEY = "/reminder.json";
+const DAY_MS = 24 * 60 * 60 * 1000;
+let reminderTimer = null;
+
+function saveReminder(reminder) {
+  return caches
+    .open(REMINDER_CACHE)
+    .then((cache) =>
+      reminder
+        ? cache.put(REMINDER_KEY, new Response(JSON.stringify(reminder)))
+        : cache.delete(REMINDER_KEY),
+    );
+}
+
+function showDueReminder() {
+  return caches
+    .open(REMINDER_CACHE)
+    .then((cache) => cache.match(REMINDER_KEY))
+    .then((response) => (response ? response.json() : null))
+    .then((reminder) => {
+      if (!reminder || reminder.at > Date.now()) {
+        return;
+      }
+      // Same time on the next day still to come; the page moves it when the
+      // goal is met or the settings change
+      let at = reminder.at + DAY_MS;
+      while (at <= Date.now()) {
+        at += DAY_MS;
+      }
+      const next = {
+        at: at,
+        body: reminder.next_body || reminder.body,
+        next_body: reminder.next_body,
+      };
+      armReminder(next);
+      return saveReminder(next).then(() =>
+        self.registration.showNotification("¡Vamos!", {
+          body: reminder.body,
+          icon: "/vamos-icon.png",
+          tag: "goal-reminder",
+        }),
+      );
+    });
+}
+
+function armReminder(reminder) {
+  clearTimeout(reminderTimer);
+  if (reminder) {
+    reminderTimer = setTimeout(
+      showDueReminder,
+      Math.max(0, reminder.at - Date.now()),
+    );
+  }
+}
+
+// A new build waits until the page asks it to take over (update banner)
+self.addEventListener("message", (event) => {
+  const data = event.data;
+  if (data === "skip-waiting") {
+    self.skipWaiting();
+    return;
+  }
+  // Anything else must be an object message; ignore strings, null, etc.
+  if (!data || typeof data !== "object") {
+    return;
+  }
+  if (data.type === "schedule-reminder") {
+    const reminder = {
+      at: data.at,
+      body: data.body,
+      next_body: data.next_body,
+    };
+    armReminder(reminder);
+    event.waitUntil(saveReminder(reminder));
+  } else if (data.type === "cancel-reminder") {
+    armReminder(null);
+    event.waitUntil(saveReminder(null));
+  }
+});
+
+self.addEventListener("periodicsync", (event) => {
+  if (event.tag === "goal-reminder") {
+    event.waitUntil(showDueReminder());
+  }
+});
+
+// Open the app, or bring it to the front, from a reminder
+self.addEventListener("notificationclick", (event) => {
+  event.notification.close();
+  event.waitUntil(
+    self.clients
+      .matchAll({ type: "window", includeUncontrolled: true })
+      .then((clients) =>
+        clients.length > 0 ? clients[0].focus() : self.clients.openWindow("/"),
+      ),
+  );
+});
+
+// Drop caches left behind by previous builds
+self.addEventListener("activate", (event) => {
+  event.waitUntil(
+    caches
+      .keys()
+      .then((keys) =>
+        Promise.all(
+          keys
+            .filter((key) => key.startsWith("vamos-") && key !== CACHE_NAME)
+            .map((key) => caches.delete(key)),
+        ),
+      )
+      .then(() => self.clients.claim()),
+  );
+});
+
+self.addEventListener("fetch", (event) => {
+  const request = event.request;
+  if (
+    request.method !== "GET" ||
+    new URL(request.url).origin !== self.location.origin
+  ) {
+    return;
+  }
+
+  // Every route is rendered client-side, so navigations get the app shell
+  if (request.mode === "navigate") {
+    event.respondWith(
+      caches
+        .match("/index.html", { cacheName: CACHE_NAME })
+        .then((cached) => cached || fetch(request)),
+    );
+    return;
+  }
+
+  event.respondWith(
+    caches
+      .match(request, { cacheName: CACHE_NAME })
+      .then((cached) => cached || fetch(request)),
+  );
+});